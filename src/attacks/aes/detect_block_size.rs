@@ -0,0 +1,64 @@
+use crate::types::{Byte, Bytes};
+
+// Longest input tried, enough for two jumps with blocks of up to 128 bytes
+const MAX_INPUT: usize = 256;
+
+// Block size of a padding mode behind an encryption oracle: grow the input
+// until the output grows, twice, the block size is the gap between both jumps
+// None when the output size does not grow with the input (fixed-size MAC, truncation)
+pub fn detect_block_size<F: FnMut(&[Byte]) -> Bytes>(mut oracle: F) -> Option<usize>
+{
+    let mut output_size = oracle(&[]).len();
+    let mut boundaries = vec![];
+
+    for n in 1..=MAX_INPUT
+    {
+        let current_size = oracle(&vec![0x0; n]).len();
+
+        if current_size > output_size
+        {
+            boundaries.push(n);
+            output_size = current_size;
+        }
+
+        if boundaries.len() == 2
+        {
+            return Some(boundaries[1] - boundaries[0]);
+        }
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::detect_block_size;
+    use crate::crypto::modes::{Mode, Ecb, Cbc};
+    use crate::crypto::modes::tests::Toy;
+    use crate::crypto::aes::{Aes, Rijndael, Key};
+    use crate::types::{Bytes, SecretBytes};
+
+    #[test]
+    fn block_ciphers()
+    {
+        let key = Key::from("YELLOW SUBMARINE");
+
+        assert_eq!(Some(16), detect_block_size( |input| Ecb::new(Aes::new(&key)).encrypt(input) ));
+        assert_eq!(Some(8), detect_block_size( |input| Cbc::new(Toy([0x2a; 8]), &[0x0; 8]).encrypt(input) ));
+
+        let key = Key::rijndael(SecretBytes::new(&[0x2a; 16]), 7);
+
+        assert_eq!(Some(28), detect_block_size( |input| Ecb::new(Rijndael::new(&key)).encrypt(input) ));
+    }
+
+    #[test]
+    fn constant_output()
+    {
+        let key = Key::from("YELLOW SUBMARINE");
+
+        // Truncating oracle, and a fixed-size MAC
+        assert_eq!(None, detect_block_size( |input| Bytes::from(&Ecb::new(Aes::new(&key)).encrypt(input)[..16]) ));
+        assert_eq!(None, detect_block_size( |_| Bytes::from([0x0; 20]) ));
+    }
+}
@@ -1,3 +1,5 @@
 mod detect_ecb;
+mod detect_block_size;
 
-pub use detect_ecb::detect_ecb;
\ No newline at end of file
+pub use detect_ecb::detect_ecb;
+pub use detect_block_size::detect_block_size;
@@ -1,84 +1,34 @@
 use std::collections::HashMap;
 use std::cmp::min;
 
-use crate::lib::num;
-use crate::lib::types::ByteVec;
-use crate::lib::crypto::xor::repeating_key;
-use crate::attacks::xor::single_byte::frequency_score;
-
-use crate::lib::traits::{Bitable, ToString, ToHex};
+use crate::num;
+use crate::types::Bytes;
+use crate::crypto::xor::repeating_key;
+use crate::stats;
+use crate::stats::PlaintextScorer;
 
 const MAX_KEY_SIZE: usize = 40;
 const KEY_BLOCKS: usize = 4;
 const TOP_KEY_SIZES: usize = 10;
 const DEFAULT_KEY_SIZE_COUNT: usize = 5;
 
-fn hamming_distance(a: &ByteVec, b: &ByteVec) -> u32
+fn score_distance(ciphertext: &Bytes) -> Vec<(usize, f64)>
 {
-    if a.len() != b.len() { panic!("ByteVecs must be the same length. ({}/{})", a.len(), b.len()); }
-
-    let a_bits = a.bits();
-    let b_bits = b.bits();
-
-    let mut distance = 0;
-
-    for i in 0..a_bits.len()
-    {
-        if a_bits[i] != b_bits[i] 
+    let mut scores: Vec<(usize, f64)> = (2..MAX_KEY_SIZE+1)
+        .map( |key_size|
         {
-            distance += 1;
-        }
-    }
-
-    return distance;
-}
-
-fn score_distance(ciphertext: &ByteVec) -> Vec<(usize, f64)>
-{
-    let mut scores: Vec<(usize, f64)> = vec![];
-
-    for key_size in 2..MAX_KEY_SIZE+1
-    {
-        let mut distances: Vec<u32> = vec![];
-
-        for n_block in 0..KEY_BLOCKS-1
-        {
-            if (n_block+1)*key_size > ciphertext.len() || (n_block+2)*key_size > ciphertext.len()
-            {
-                //println!("Block outside ciphertext, skipping.");
-                continue;
-            }
-
-            let a_block = &ciphertext[n_block*key_size..(n_block+1)*key_size].to_vec();
-            let b_block = &ciphertext[(n_block+1)*key_size..(n_block+2)*key_size].to_vec();
-
-            let dist = hamming_distance(&a_block, &b_block);
-
-            //println!("Distance [{0}..{1}]-[{1}..{2}] ({3}) = {4}", n_block*key_size, (n_block+1)*key_size, (n_block+2)*key_size, key_size, dist);
-
-            distances.push(dist);
-        }
-
-        let total_distance: u32 = distances.iter().sum();        
-        let score = total_distance as f64 / (key_size * distances.len()) as f64;
-
-        //println!("=> Normalized distance [{}] = {} (td : {})", key_size, score, total_distance);
-
-        scores.push((key_size, score));
-    }   
+            let distance = stats::normalized_hamming_distance(ciphertext, key_size, KEY_BLOCKS);
+            (key_size, distance.unwrap_or(f64::INFINITY))
+        })
+        .collect();
 
     scores.sort_by( |a,b| a.1.partial_cmp(&b.1).unwrap() );
 
-    // for kv in &scores
-    // {
-    //     println!("Size: {} ({})", kv.0, kv.1)
-    // }
-
     return scores;
 }
 
 //TODO: refactor this ugly bit
-fn guess_key_size(ciphertext: &ByteVec) -> Vec<(usize, f64)>
+fn guess_key_size(ciphertext: &Bytes) -> Vec<(usize, f64)>
 {
     let scores = score_distance(ciphertext);
     let top_key_sizes = &scores[0..TOP_KEY_SIZES];
@@ -96,7 +46,7 @@ fn guess_key_size(ciphertext: &ByteVec) -> Vec<(usize, f64)>
     }
 
     // Find the most-frequent GCD
-    let mut mfgcd = *gcds.keys().nth(0).unwrap();
+    let mut mfgcd = *gcds.keys().next().unwrap();
 
     for (gcd, count) in &gcds
     {
@@ -107,37 +57,36 @@ fn guess_key_size(ciphertext: &ByteVec) -> Vec<(usize, f64)>
     }
 
     // If the MF-GCD is in the top key_sizes, return it
-    if let Some(_) = top_key_sizes.iter().find( |t| t.0 == mfgcd as usize )
+    if top_key_sizes.iter().any( |t| t.0 == mfgcd as usize )
     {
         return vec![(mfgcd as usize, 1.0)];
     }
     // Else return the littlest normalized-distance key_size
     else
     {
-        // println!("[DEBUG] Default return key_size");
         return scores[0..DEFAULT_KEY_SIZE_COUNT].to_vec();
     }
 }
 
-fn slice_block(ciphertext: &ByteVec, block_size: usize) -> Vec<ByteVec>
+fn slice_block(ciphertext: &Bytes, block_size: usize) -> Vec<Bytes>
 {
-    let mut result: Vec<ByteVec> = vec![];
+    let mut result: Vec<Bytes> = vec![];
 
     for i in (0..ciphertext.len()).step_by(block_size)
     {
-        result.push(ciphertext[i..min(i+block_size, ciphertext.len())].to_vec());
+        result.push(Bytes::from(&ciphertext[i..min(i+block_size, ciphertext.len())]));
     }
 
     return result;
 }
 
-fn transpose(blocks: &Vec<ByteVec>) -> Vec<ByteVec>
+fn transpose(blocks: &[Bytes]) -> Vec<Bytes>
 {
     let mut result = vec![];
 
     for i in 0..blocks[0].len()
     {
-        let mut bytes = ByteVec::new();
+        let mut bytes = Bytes::new();
 
         for j in 0..blocks.len()
         {
@@ -153,75 +102,52 @@ fn transpose(blocks: &Vec<ByteVec>) -> Vec<ByteVec>
     return result;
 }
 
-fn guess_key(ciphertext: &ByteVec, key_size: usize) -> ByteVec
+fn guess_key(ciphertext: &Bytes, key_size: usize, scorer: &dyn PlaintextScorer) -> Bytes
 {
     // Break cipher_text into block of *key_size*
     let blocks = slice_block(ciphertext, key_size);
 
-    // Break cipher_text into block of *key_size*
-    let blocks = slice_block(ciphertext, key_size);
-
     // Transpose blocks
     let transposed_blocks = transpose(&blocks);
 
-    println!("[DEBUG] Decrypting blocks with a key size of {} ...", key_size);
-
     // Run single-byte attacks on transposed blocks
-    let mut key = ByteVec::new();
+    let mut key = Bytes::new();
 
     for block in transposed_blocks
     {
-        // println!("=== START BLOCK ===");
-        let decrypted_block = super::single_byte::decrypt(&block);
+        let decrypted_block = super::single_byte::decrypt(&block, scorer);
         key.push(decrypted_block.0);
     }
 
     return key;
 }
 
-pub fn decrypt(ciphertext: &ByteVec) -> (ByteVec, ByteVec)
+// (key, plaintext, score) for each key size tried, best score first
+// A single candidate means the key size was found with high confidence
+pub fn candidates(ciphertext: &Bytes, scorer: &dyn PlaintextScorer) -> Vec<(Bytes, Bytes, f64)>
 {
     // Find key_size by computing hamming distance for blocks (search min distance normalized)
     let key_sizes = guess_key_size(ciphertext);
 
-    if key_sizes.len() == 1
-    {
-        let key_size = key_sizes[0].0;
-        
-        println!("[DEBUG] High-confidence key size found : {}", key_size);
-        let key = guess_key(ciphertext, key_size);
-        let plaintext = repeating_key(ciphertext, &key);
-
-        return (key, plaintext);
-    }
-
-    println!("[DEBUG] No high-confidence key size found, trying top-{} key sizes...", key_sizes.len());
-
-    let mut scores: Vec<(ByteVec, ByteVec, f64)> = vec![];
+    let mut scores: Vec<(Bytes, Bytes, f64)> = vec![];
 
     for (key_size, _) in key_sizes
     {
-        let key       = guess_key(ciphertext, key_size);
+        let key       = guess_key(ciphertext, key_size, scorer);
         let plaintext = repeating_key(ciphertext, &key);
-        let plaintext_score = frequency_score(&plaintext);
+        let plaintext_score = scorer.score(&plaintext);
 
         scores.push((key, plaintext, plaintext_score));
     }
 
     scores.sort_by( |a, b| b.2.partial_cmp(&a.2).unwrap() );
 
-    for score in &scores
-    {
-        let extract = match score.1.to_string()
-        {
-            Ok(string) => string[..min(10, string.len())].to_string(),
-            Err(_) => String::from("ERR"),
-        };
-
-        println!("Score: {} / Plain text (extract): {} [...] / Key: {}", score.2, extract, score.0.to_hex());
-    }
+    return scores;
+}
 
-    let result = scores.remove(0);
+pub fn decrypt(ciphertext: &Bytes, scorer: &dyn PlaintextScorer) -> (Bytes, Bytes)
+{
+    let result = candidates(ciphertext, scorer).remove(0);
 
     return (result.0, result.1);
 }
@@ -229,44 +155,32 @@ pub fn decrypt(ciphertext: &ByteVec) -> (ByteVec, ByteVec)
 #[cfg(test)]
 mod tests
 {
-    use crate::lib::types::ByteVec;
-    use crate::lib::traits::FromHex;
-
-    #[test]
-    fn hamming_distance()
-    {
-        assert_eq!(
-            37,
-            super::hamming_distance(
-                &ByteVec::from("this is a test"), 
-                &ByteVec::from("wokka wokka!!!")
-            )
-        )
-    }
+    use crate::types::Bytes;
+    use crate::traits::FromHex;
 
     #[test]
     fn slice_block()
     {
         assert_eq!(
             vec![
-                ByteVec::from_hex("010203"),
-                ByteVec::from_hex("040506"),
-                ByteVec::from_hex("070809")
+                Bytes::from_hex("010203"),
+                Bytes::from_hex("040506"),
+                Bytes::from_hex("070809")
             ],
             super::slice_block(
-                &ByteVec::from_hex("010203040506070809"), 
+                &Bytes::from_hex("010203040506070809"), 
                 3
             )
         );
 
         assert_eq!(
             vec![
-                ByteVec::from_hex("01020304"),
-                ByteVec::from_hex("05060708"),
-                ByteVec::from_hex("09")
+                Bytes::from_hex("01020304"),
+                Bytes::from_hex("05060708"),
+                Bytes::from_hex("09")
             ],
             super::slice_block(
-                &ByteVec::from_hex("010203040506070809"), 
+                &Bytes::from_hex("010203040506070809"), 
                 4
             )
         );
@@ -277,31 +191,31 @@ mod tests
     {
         assert_eq!(
             vec![
-                ByteVec::from_hex("0105"),
-                ByteVec::from_hex("0206"),
-                ByteVec::from_hex("0307"),
-                ByteVec::from_hex("0408"),
+                Bytes::from_hex("0105"),
+                Bytes::from_hex("0206"),
+                Bytes::from_hex("0307"),
+                Bytes::from_hex("0408"),
             ],
             super::transpose(
-                &vec![
-                    ByteVec::from_hex("01020304"),
-                    ByteVec::from_hex("05060708")
+                &[
+                    Bytes::from_hex("01020304"),
+                    Bytes::from_hex("05060708")
                 ]
             )
         );
 
         assert_eq!(
             vec![
-                ByteVec::from_hex("010509"),
-                ByteVec::from_hex("02060A"),
-                ByteVec::from_hex("03070B"),
-                ByteVec::from_hex("0408"),
+                Bytes::from_hex("010509"),
+                Bytes::from_hex("02060A"),
+                Bytes::from_hex("03070B"),
+                Bytes::from_hex("0408"),
             ],
             super::transpose(
-                &vec![
-                    ByteVec::from_hex("01020304"),
-                    ByteVec::from_hex("05060708"),
-                    ByteVec::from_hex("090A0B"),
+                &[
+                    Bytes::from_hex("01020304"),
+                    Bytes::from_hex("05060708"),
+                    Bytes::from_hex("090A0B"),
                 ]
             )
         );
@@ -1,30 +1,31 @@
-use crate::lib::types::{Byte, ByteVec};
-use crate::lib::traits::{Random};
-use crate::lib::crypto::aes;
-use crate::attacks;
+use cryptopals::types::{Byte, Bytes};
+use cryptopals::traits::{Random};
+use cryptopals::crypto::aes;
+use cryptopals::attacks;
 
 use rand::Rng;
 
-fn encryption_oracle(input: &[Byte]) -> (ByteVec, aes::AesMode)
+fn encryption_oracle<R: Rng + ?Sized>(input: &[Byte], rng: &mut R) -> (Bytes, aes::AesMode)
 {
-    let mut rng = rand::thread_rng();
-
-    let mut data = ByteVec::random(rng.gen_range(5, 11));
+    let prefix_size = rng.gen_range(5, 11);
+    let mut data = Bytes::random(prefix_size, rng);
     data.extend(input);
-    data.extend(ByteVec::random(rng.gen_range(5, 11)));
 
-    let msg = aes::Message::from(data, aes::Key::new(&ByteVec::random(16)))
-                            .with_iv(ByteVec::random(16));
+    let suffix_size = rng.gen_range(5, 11);
+    data.extend(Bytes::random(suffix_size, rng));
+
+    let msg = aes::Message::from(data, aes::Key::random(16, rng))
+                            .with_random_iv(rng);
 
     match rng.gen_range(1, 3)
     {
         1 => (aes::encrypt(aes::AesMode::ECB, &msg), aes::AesMode::ECB),
         2 => (aes::encrypt(aes::AesMode::CBC, &msg), aes::AesMode::CBC),
-        _ => (vec![], aes::AesMode::ECB) // Not possible
+        _ => (Bytes::new(), aes::AesMode::ECB) // Not possible
     }
 }
 
-pub fn challenge11()
+pub fn challenge11<R: Rng + ?Sized>(rng: &mut R)
 {
     println!("=== [ Challenge 11 ] ===");
 
@@ -37,7 +38,7 @@ pub fn challenge11()
     for r in 0..ROUNDS
     {
         let chosen_input = vec![0xA; INPUT_SIZE];
-        let encryption = encryption_oracle(&chosen_input);
+        let encryption = encryption_oracle(&chosen_input, rng);
 
         let score = attacks::aes::detect_ecb(&encryption.0, 16);
         let detected_mode = match score > THRESHOLD
@@ -1,7 +1,9 @@
-use crate::lib::types::{Byte, ByteVec};
-use crate::lib::traits::{Random, FromBase64, ToHex, BlockIterable, ToString};
-use crate::lib::crypto::aes;
-use crate::attacks;
+use cryptopals::types::{Byte, Bytes};
+use cryptopals::traits::{Random, FromBase64, BlockIterable};
+use cryptopals::crypto::aes;
+use cryptopals::attacks;
+
+use rand::Rng;
 
 use std::collections::HashMap;
 use std::time::Instant;
@@ -28,62 +30,28 @@ macro_rules! debugln
     }
 }
 
-fn encryption_oracle(input: &[Byte], key: &[Byte]) -> ByteVec
+fn encryption_oracle(input: &[Byte], key: &[Byte]) -> Bytes
 {
-    let mut data = input.to_vec();
-    data.extend(ByteVec::from_base64(UNKNOWN_STRING));
+    let mut data = Bytes::from(input);
+    data.extend(Bytes::from_base64(UNKNOWN_STRING));
 
     let msg = aes::Message::from(data, aes::Key::new(key));
 
     aes::encrypt(aes::AesMode::ECB, &msg)
 }
 
-fn detect_block_size() -> usize
-{
-    let tmp_key = ByteVec::random(16);
-    let mut output_size = encryption_oracle(&vec![], &tmp_key).len();
-
-    debugln!("Output size with zero-input : {}", output_size);
-
-    let mut n = 0;
-    let mut sizes = vec![];
-
-    loop
-    {
-        n += 1;
-
-        let current_size = encryption_oracle(&vec![0x0; n], &tmp_key).len();
-
-        // If adding a byte changes output size, we have found a block boundary
-        if current_size > output_size
-        {   
-            debugln!("Found a block boundary when adding {} bytes", n);
-
-            sizes.push(n);
-            output_size = current_size;
-        }   
-
-        if sizes.len() >= 2 { break }
-    }
-
-    let block_size = sizes[1] - sizes[0];
-    println!("Block size : {}", block_size);
-
-    block_size
-}
-
-fn build_dictionnary(n_block: usize, block_size: usize, input: &ByteVec, key: &[Byte]) -> HashMap<ByteVec, Byte>
+fn build_dictionnary(n_block: usize, block_size: usize, input: &Bytes, key: &[Byte]) -> HashMap<Bytes, Byte>
 {
     println!("### Building dictionnary");
 
-    let mut rainbow = HashMap::<ByteVec, Byte>::new();
+    let mut rainbow = HashMap::<Bytes, Byte>::new();
 
     for b in 0x00..=0xFF
     {
         let crafted = [&input[..], &[b]].concat();
-        let cipher  = encryption_oracle(&crafted, &key).blocks(block_size).nth(n_block).unwrap();
+        let cipher  = encryption_oracle(&crafted, key).blocks(block_size).nth(n_block).unwrap();
 
-        debugln!("oracle(input || 0x{:0>2x})[{}] = {}...", b, n_block, cipher.to_hex());
+        debugln!("oracle(input || 0x{:0>2x})[{}] = {}...", b, n_block, cipher);
 
         rainbow.insert(cipher, b);
     }
@@ -93,16 +61,20 @@ fn build_dictionnary(n_block: usize, block_size: usize, input: &ByteVec, key: &[
     rainbow
 }
 
-pub fn challenge12()
+pub fn challenge12<R: Rng + ?Sized>(rng: &mut R)
 {
     println!("=== [ Challenge 12 ] ===");
 
-    let key = ByteVec::random(16);
+    let key = Bytes::random(16, rng);
 
-    println!("Consistent key : {}", key.to_hex());    
+    println!("Consistent key : {}", key);    
 
     println!("\n# Detecting block size");
-    let block_size = detect_block_size();
+    let tmp_key    = Bytes::random(16, rng);
+    let block_size = attacks::aes::detect_block_size( |input| encryption_oracle(input, &tmp_key) )
+        .expect("No block size found");
+
+    println!("Block size : {}", block_size);
 
     println!("\n# Detecting AES mode");
     let ciphertext = encryption_oracle(&vec![0x0; block_size * 4], &key);
@@ -111,13 +83,13 @@ pub fn challenge12()
     println!("ECB mode : {}", ecb_score > 0.0);
 
     println!("\n# Finding number of blocks to decrypt");
-    let n_blocks_to_decrypt = encryption_oracle(&vec![], &key).blocks(block_size).count();
+    let n_blocks_to_decrypt = encryption_oracle(&[], &key).blocks(block_size).count();
 
     println!("Blocks to decrypt : {}", n_blocks_to_decrypt);
 
     println!("\n# Decrypting one byte at a time");
 
-    let mut plaintext: ByteVec = vec![];
+    let mut plaintext = Bytes::new();
 
     for block in 0..n_blocks_to_decrypt
     {
@@ -128,18 +100,18 @@ pub fn challenge12()
             let start = Instant::now();
 
             let pre_block     = vec![0x0; block_size - 1 - i];
-            let crafted_input = [&pre_block, &plaintext[..]].concat();
+            let crafted_input = Bytes::from([&pre_block, &plaintext[..]].concat());
             let needle        = encryption_oracle(&pre_block, &key).blocks(block_size).nth(block).unwrap();
 
-            println!("Crafted input : {}", crafted_input.to_hex());
-            println!("Needle        : {}", needle.to_hex());
+            println!("Crafted input : {}", crafted_input);
+            println!("Needle        : {}", needle);
 
             let rainbow = build_dictionnary(block, block_size, &crafted_input, &key);
 
             let decrypted_byte = rainbow.get(&needle);
 
             // Last block, we are hitting the padding
-            if decrypted_byte == None && block == n_blocks_to_decrypt - 1
+            if decrypted_byte.is_none() && block == n_blocks_to_decrypt - 1
             {
                 debugln!("Hitting padding, stopping !");
                 break;
@@ -152,5 +124,5 @@ pub fn challenge12()
         }
     }
 
-    println!("\n# Decrypting done\nPlaintext :\n{}", plaintext.to_string().unwrap_or(String::from("Non UTF8")));
+    println!("\n# Decrypting done\nPlaintext :\n{}", plaintext.to_utf8().unwrap_or(String::from("Non UTF8")));
 }
@@ -1,6 +1,9 @@
-use crate::lib::types::ByteVec;
-use crate::lib::crypto::aes;
-use crate::lib::traits::{ToString, ToHex, Random};
+use cryptopals::types::Bytes;
+use cryptopals::crypto::aes;
+use cryptopals::attacks;
+use cryptopals::traits::Random;
+
+use rand::Rng;
 
 fn profile_for(email: &str) -> String
 {
@@ -9,56 +12,26 @@ fn profile_for(email: &str) -> String
     format!("email={0}&uid=10&role=user", cleaned_email)
 }
 
-fn encrypt_profile(profile: &str, key: &ByteVec) -> ByteVec
+fn encrypt_profile(profile: &str, key: &Bytes) -> Bytes
 {
-    let content = ByteVec::from(profile);
+    let content = Bytes::from(profile);
     aes::encrypt(aes::AesMode::ECB, &aes::Message::from(content, aes::Key::new(key)))
 }
 
-fn decrypt_profile(content: &ByteVec, key: &ByteVec) -> String
+fn decrypt_profile(content: &Bytes, key: &Bytes) -> String
 {
     let profile = aes::decrypt(
         aes::AesMode::ECB, 
         &aes::Message::from(
-            content.to_vec(), 
+            content.clone(), 
             aes::Key::new(key)
         )
     );
     
-    profile.to_string().unwrap()
-}
-
-fn detect_block_size() -> usize
-{
-    let tmp_key = ByteVec::random(16);
-    let mut output_size = encrypt_profile(&"", &tmp_key).len();
-
-    let mut n = 0;
-    let mut sizes = vec![];
-
-    loop
-    {
-        n += 1;
-
-        let current_size = encrypt_profile(&"A".repeat(n), &tmp_key).len();
-
-        // If adding a byte changes output size, we have found a block boundary
-        if current_size > output_size
-        {   
-            sizes.push(n);
-            output_size = current_size;
-        }   
-
-        if sizes.len() >= 2 { break }
-    }
-
-    let block_size = sizes[1] - sizes[0];
-    println!("Block size : {}", block_size);
-
-    block_size
+    profile.to_utf8().unwrap()
 }
 
-fn get_admin_block(block_size: usize, key: &ByteVec) -> ByteVec
+fn get_admin_block(block_size: usize, key: &Bytes) -> Bytes
 {
     let n_missing = block_size - "email=".len();
     let crafted   = format!(
@@ -69,37 +42,41 @@ fn get_admin_block(block_size: usize, key: &ByteVec) -> ByteVec
     );
     let profile   = profile_for(&crafted);
 
-    let encrypted = encrypt_profile(&profile, &key);
+    let encrypted = encrypt_profile(&profile, key);
     let start = "email=".len() + n_missing;
 
-    encrypted[start..start + block_size].to_vec()
+    Bytes::from(&encrypted[start..start + block_size])
 }
 
-fn get_padding_block(block_size: usize, key: &ByteVec) -> ByteVec
+fn get_padding_block(block_size: usize, key: &Bytes) -> Bytes
 {
     let n_missing = block_size - ("email=&uid=10&role=user".len() % block_size);
     let profile   = profile_for(&"A".repeat(n_missing));
 
     println!("[PADDING] Crafted : {}", profile);
 
-    let encrypted = encrypt_profile(&profile, &key);
+    let encrypted = encrypt_profile(&profile, key);
 
-    encrypted[encrypted.len() - block_size..].to_vec()
+    Bytes::from(&encrypted[encrypted.len() - block_size..])
 }
 
-pub fn challenge13()
+pub fn challenge13<R: Rng + ?Sized>(rng: &mut R)
 {
     println!("=== [Challenge 13] ===");
 
     let email = "foo@bar.com";
-    let key   = ByteVec::random(16);
+    let key   = Bytes::random(16, rng);
 
-    println!("Consistent key : {}", key.to_hex());
+    println!("Consistent key : {}", key);
     println!("Email : {}", email);
 
     let profile = profile_for(email);
 
-    let block_size = detect_block_size();
+    let tmp_key    = Bytes::random(16, rng);
+    let block_size = attacks::aes::detect_block_size( |input| encrypt_profile(&"A".repeat(input.len()), &tmp_key) )
+        .expect("No block size found");
+
+    println!("Block size : {}", block_size);
 
     // TODO
     // 1. Craft a block where 'admin           ' is on a full block (cut&paste)
@@ -118,13 +95,13 @@ pub fn challenge13()
     let encrypted_profile    = encrypt_profile(&crafted_profile, &key);
 
     println!("Crafted profile : {}", crafted_profile);
-    println!("Encrypted profile : {}", encrypted_profile.to_hex());
+    println!("Encrypted profile : {}", encrypted_profile);
 
-    let mut morphed_profile = encrypted_profile[..(base_size + crafted_email.len())].to_vec();
+    let mut morphed_profile = Bytes::from(&encrypted_profile[..(base_size + crafted_email.len())]);
     morphed_profile.extend(admin_block);
     morphed_profile.extend(padding_block);
 
-    println!("Morphed encrypted profile : {}", morphed_profile.to_hex());
+    println!("Morphed encrypted profile : {}", morphed_profile);
 
     let hacked_profile = decrypt_profile(&morphed_profile, &key);
     println!("Hacked profile : {}", hacked_profile);
@@ -6,23 +6,55 @@ pub use challenge11::challenge11;
 pub use challenge12::challenge12;
 pub use challenge13::challenge13;
 
-use crate::attacks;
-use crate::lib;
+use cryptopals::attacks;
 use std::fs;
 use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Read};
+use std::process;
 
-use lib::types::ByteVec;
-use lib::traits::{FromHex, ToString, FromBase64, ToHex};
-use lib::crypto::aes;
+use cryptopals::types::Bytes;
+use cryptopals::traits::{FromHex, FromBase64, ToHex};
+use cryptopals::crypto::aes;
+use cryptopals::io::Base64Reader;
+use cryptopals::stats::Unigram;
+
+fn read_base64_file(path: &str) -> Bytes
+{
+    let mut ciphertext = vec![];
+    let result = Base64Reader::new(File::open(path).unwrap()).read_to_end(&mut ciphertext);
+
+    let e = match result
+    {
+        Ok(_) => return Bytes::from(ciphertext),
+        Err(e) => e
+    };
+
+    // Translate the byte offset into line:column for the report
+    let (line, column) = match cryptopals::io::codec_error(&e).and_then( |c| c.position() )
+    {
+        Some(p) =>
+        {
+            let before = &fs::read(path).unwrap()[..p];
+            let line   = before.iter().filter( |b| **b == b'\n' ).count() + 1;
+            let column = before.iter().rev().take_while( |b| **b != b'\n' ).count() + 1;
+
+            (line, column)
+        },
+        None => (0, 0)
+    };
+
+    eprintln!("{}:{}:{}: {}", path, line, column, e);
+    process::exit(1);
+}
 
 pub fn challenge3()
 {
     let result = attacks::xor::single_byte::decrypt(
-        &ByteVec::from_hex("1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736")
+        &Bytes::from_hex("1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736"),
+        &Unigram::english()
     );
 
-    println!("Score: {}\nKey: 0x{:0>2x}\nPlain text:{}", result.2, result.0, result.1.to_string().unwrap());
+    println!("Score: {}\nKey: 0x{:0>2x}\nPlain text:{}", result.2, result.0, result.1.to_utf8().unwrap());
 }
 
 pub fn challenge4()
@@ -33,19 +65,28 @@ pub fn challenge4()
     let lines: Vec<String> = io::BufReader::new(file).lines().map( |l| l.unwrap()).collect();
     let line_count = lines.len();
 
-    let mut decrypted_lines: Vec<(usize, (u8, ByteVec, f64))> = vec![];
+    let mut decrypted_lines: Vec<(usize, (u8, Bytes, f64))> = vec![];
+    let scorer = Unigram::english();
 
     println!("Loaded {} lines from file.", line_count);
     println!("Decrypting...");
 
     for (i, line) in lines.iter().enumerate()
     {
+        let ciphertext = match Bytes::try_from_hex(line)
+        {
+            Ok(bytes) => bytes,
+            Err(e) =>
+            {
+                eprintln!("{}:{}: skipping line, {}", FILE_PATH, i + 1, e);
+                continue;
+            }
+        };
+
         decrypted_lines.push(
             (
                 i,
-                attacks::xor::single_byte::decrypt(
-                    &ByteVec::from_hex(&line)
-                )
+                attacks::xor::single_byte::decrypt(&ciphertext, &scorer)
             )
         );
     }
@@ -61,7 +102,7 @@ pub fn challenge4()
         first_result.0 + 1, lines[first_result.0],
         guess.2, 
         guess.0, 
-        guess.1.to_string().unwrap()
+        guess.1.to_utf8().unwrap()
     );
 }
 
@@ -69,27 +110,48 @@ pub fn challenge6()
 {
     const FILE_PATH: &str = "./data/6.txt";
 
-    let content = fs::read_to_string(FILE_PATH).unwrap();
-    let ciphertext = ByteVec::from_base64(&content);
+    let ciphertext = read_base64_file(FILE_PATH);
 
     // Key : 37e02c95
-    // let ciphertext = ByteVec::from_base64(
+    // let ciphertext = Bytes::from_base64(
     //     "e49e8FrAReVElUG1U49A+kXAX/xDwE34UpQAtVSPQuZSg1jwQ5VetVaEReVek0/
     //     8WYcM8FuJWLsXpEP7UoMM9FTAQPRYkknwQ8BY4EWQReYZwH/wU8BJ81GJT/xDlV6
     //     1R4hN51KUXvQXjE32QpMAtUGFQLVUj0LxXo1J+0OVQbVSkkPmF4ND+1CVSbs="
     // );
 
     // Key : 633bb151099a4141b4c040cebb2ba043
-    let ciphertext = ByteVec::from_base64(
+    let ciphertext = Bytes::from_base64(
         "KhvXPnz0JWHV4CyhzU6qBQxJkTxskAUgxqwpoNwHgCkWSMVxbfM3JJSyKanTX4AqDTHwP226Jy7YrC+5m0bZYw9e0DUDzSQt2Oxgh5tNzzYNX5EwKf0oM9jKAqvaXtQqBU7dcWj0JWHHtyWrzyHvK08b+HFn/zckxuAroN5cgDoMTpEmbOgkYcCoJe7IRM0mDFXUW177KDXdrifu3UTSYw5eu3ZK+zQy0eA3q5tcxTEGG9skeu5hKt2kM+7MQ8UtQ0zUcW//LS2UqS7u10TWJml13iUp8S8uw6kuqZtcyCIXG9glKe0gMr6JYLnSR8xjDVTFcW7zNySUuS+7m17QSTdT2CIp7igs0coCu88LxCIRV9g/brZhK8GzNO7QQtMwQ1bUcXr2Lja+mS+7yQvIJgJJxXFg6WEg2Kxgh5tE1y1pet81KfMvYc2vNbybTtkmEBeRKGbvZjPR4Cih10/JLQQb3Dhn/0sD1aI54ptihy5pf9A/avMvJpSpLu7PQ8VjB1rDOgPNKDXc4DmhzgvCJhdM1DRnuiw4lKEyo8gh4iIRXtc+Zu5hLtrgNKbeC8cxAkjCW0XzMjXRrimg3AvULENUxCMp/CA327Iput4L0ywNXLsGYf8vYc2vNe7ISsknQ0LeJCn2Li7fpSTu2gvNJhBIuxgp7Skox7AlvN5PgDYNX9QjZ/8gNdzgLbebSdImAk/ZW0vvNWHNrzXu007BMQcb2CUD3iAz2KkuqZcL2SwWG90+ZvFhMdGyJqvYX4xjF1TfOG7yNUvjpSyim2KAJQxO3zUp+2E2260hoLF41DEMVdY0e7o1KdWuYK/VUs8tBhv4cWL0Lja+kyirm1jIIhFewnFk42ElxqUho8gh6WMLVME0Ke4pIMDgM6HWTsQiGjH4dmX2YTLcoTKrm0PFMUNT3jxskAhh0q81oN8LwWMPVMc0A84uYdehMrzCC80sEV6RJWH7L2HetTO6m0bZYxBe0iNs7jJL4K9grdpZ0jpDV94nbJAVLpSjIbzJUoAgC1LdNXv/L0v7pmChzlmALBRVuwZsuiAz0eAzutJHzGMIUtUiJbojNMDgN6ucWcVjEFSROGe6LS7CpUqI0kzINwpV1nFo/SAo2rM07tpHzGMMX9UiA9NhKtqvN+7MTocvDxvTNCn7LTPdpyi6sX/IKhAbxThk/0sF1bIsp9VMjGMJTsIlKfIuLdDgLbebQ8EtBzHzNCn3OGHTqTKilwvpZA9XkTNsujguwbJgo9pFqgpDSNQ0Kfc4YdK1NLvJToAqDRvIPnzoYSTNpTPE+UrCOk8b+HZkkAUg2qMpoNwLyS1DT9k0Kf4gM9/KF6fPQ4A6DE6RM2zuNiTRrmCjwgvBMQ5IuxNo6CQn26807tRFgDcLXpE2e/syMr6MKb3PTs4qDVyRJWa6LjTG4CavzUTSKhdekSJm9CZL46gloJtigDACTJEoZu9hKNrgNKbaX4AnEV7CIiW6LS7bqymg3AvTLENZ1DB87ignwaxKh5tPzy1ET5E1bOkkM8KlYLrTQtNJJ1rDPWD0Jm2UuS+7m0fPLAgbwTR7/CQiwOxgutRFySQLT7sTaPg4bZSJZ6Oxb8EtAFLfNinzL2HAqCXu30rSKGls2CVhujguweAiq89cxSYNG9woKfszLMfKAq/JTsYsDE+RPme6NSnR4Ce82ljTSS9SwiVs9Cgv0+A0oZtE1TFDXdAnZugoNdHgM6HVTKoKQ1PQJ2y6JyDdtCju0kWANAtaxXFAujIk0coOocwL6WMIVd4mKdNhKdW2Je7WTtRjAlWRMGf9JC2UqS7uy07SMAxVuxBn/mEy3KVgotREyzBDS9Qjb/8iNb6JYKrURYc3Q1/UImzoNySUtCinyCH5LBYb3T5m8WEx0bImq9hfgDcMVdg2Ye4="
     );
 
-    let result = attacks::xor::repeating_key::decrypt(&ciphertext);
+    let mut candidates = attacks::xor::repeating_key::candidates(&ciphertext, &Unigram::english());
+
+    if candidates.len() == 1
+    {
+        println!("High-confidence key size found : {}", candidates[0].0.len());
+    }
+    else
+    {
+        println!("No high-confidence key size found, tried top-{} key sizes:", candidates.len());
+
+        for (key, plaintext, score) in &candidates
+        {
+            let extract = match plaintext.to_utf8()
+            {
+                Ok(string) => string.chars().take(10).collect(),
+                Err(_)     => String::from("ERR"),
+            };
+
+            println!("Score: {} / Plain text (extract): {} [...] / Key: {}", score, extract, key);
+        }
+    }
+
+    let result = candidates.remove(0);
 
     println!(
         "=== Guess ===\nKey: 0x{}\nPlain text:{}",
-        result.0.to_hex(),
-        result.1.to_string().unwrap_or(String::from("..."))
+        result.0,
+        result.1.to_utf8().unwrap_or(String::from("..."))
     );
 }
 
@@ -97,8 +159,7 @@ pub fn challenge7()
 {
     const FILE_PATH: &str = "./data/7.txt";
 
-    let content = fs::read_to_string(FILE_PATH).unwrap();
-    let ciphertext = ByteVec::from_base64(&content);
+    let ciphertext = read_base64_file(FILE_PATH);
 
     let key = aes::Key::from("YELLOW SUBMARINE");
     let msg = aes::Message::from(ciphertext, key);
@@ -107,8 +168,8 @@ pub fn challenge7()
 
     println!(
         "=== Decrypt ===\nKey: {}\nPlain text:\n{}", 
-        msg.key, 
-        plaintext.to_string().unwrap_or(String::from("NON UTF-8"))
+        msg.key.secret().reveal(),
+        plaintext.to_utf8().unwrap_or(String::from("NON UTF-8"))
     );
 }
 
@@ -117,14 +178,26 @@ pub fn challenge8()
     const FILE_PATH: &str = "./data/8.txt";
 
     let file = File::open(FILE_PATH).unwrap();
-    let ciphertexts: Vec<ByteVec> = io::BufReader::new(file).lines().map( |l| ByteVec::from_hex(&l.unwrap()) ).collect();
+    let ciphertexts: Vec<Bytes> = io::BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter_map( |(i, l)| match Bytes::try_from_hex(&l.unwrap())
+        {
+            Ok(bytes) => Some(bytes),
+            Err(e) =>
+            {
+                eprintln!("{}:{}: skipping line, {}", FILE_PATH, i + 1, e);
+                None
+            }
+        })
+        .collect();
 
     let mut results = vec![];
 
     for (i, cipher) in ciphertexts.iter().enumerate()
     {
-        let score = attacks::aes::detect_ecb(&cipher, 16);
-        results.push((i, cipher.to_vec(), score));
+        let score = attacks::aes::detect_ecb(cipher, 16);
+        results.push((i, cipher.clone(), score));
     }
 
     results.sort_by( |a,b| b.2.partial_cmp(&a.2).unwrap() );
@@ -146,13 +219,12 @@ pub fn challenge10()
 
     const FILE_PATH: &str = "./data/10.txt";
 
-    let content = fs::read_to_string(FILE_PATH).unwrap();
-    let ciphertext = ByteVec::from_base64(&content);
+    let ciphertext = read_base64_file(FILE_PATH);
 
     let msg = aes::Message::from(ciphertext, aes::Key::from("YELLOW SUBMARINE"))
-        .with_iv(vec![0x0; 16]);
+        .with_iv(Bytes::from([0x0; 16]));
 
     let plaintext = aes::decrypt(aes::AesMode::CBC, &msg);
 
-    println!("Decrypted message :\n{}", plaintext.to_string().unwrap_or(String::from("Non UTF8")));
+    println!("Decrypted message :\n{}", plaintext.to_utf8().unwrap_or(String::from("Non UTF8")));
 }
\ No newline at end of file
@@ -0,0 +1,137 @@
+use crate::types::Byte;
+
+// AES-NI instructions (x86_64 only), checked at runtime
+// Blocks and round keys are loaded as is, the instructions use the FIPS 197 byte order
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[cfg(target_arch = "x86_64")]
+pub fn is_available() -> bool
+{
+    is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub fn is_available() -> bool
+{
+    false
+}
+
+fn check(data: &[Byte])
+{
+    if !is_available() { panic!("AES-NI is not supported by this CPU"); }
+
+    if !data.len().is_multiple_of(16) { panic!("Malformed data ({} is not a multiple of 16)", data.len()); }
+}
+
+pub fn encrypt_block(round_keys: &[[Byte; 16]], block: &mut [Byte; 16])
+{
+    encrypt_blocks(round_keys, block);
+}
+
+// round_keys must be the equivalent inverse cipher schedule, in decryption order
+pub fn decrypt_block(round_keys: &[[Byte; 16]], block: &mut [Byte; 16])
+{
+    decrypt_blocks(round_keys, block);
+}
+
+// data must be a multiple of 16 bytes
+pub fn encrypt_blocks(round_keys: &[[Byte; 16]], data: &mut [Byte])
+{
+    check(data);
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe { x86::encrypt(round_keys, data) }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = round_keys;
+}
+
+pub fn decrypt_blocks(round_keys: &[[Byte; 16]], data: &mut [Byte])
+{
+    check(data);
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe { x86::decrypt(round_keys, data) }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = round_keys;
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86
+{
+    use super::*;
+
+    // Blocks in flight at once, to hide the instruction latency
+    const LANES: usize = 8;
+
+    unsafe fn load_keys(round_keys: &[[Byte; 16]]) -> [__m128i; 15]
+    {
+        let mut keys = [_mm_setzero_si128(); 15];
+
+        for (i, round_key) in round_keys.iter().enumerate()
+        {
+            keys[i] = load(round_key);
+        }
+
+        return keys;
+    }
+
+    unsafe fn load(bytes: &[Byte]) -> __m128i
+    {
+        _mm_loadu_si128(bytes.as_ptr() as *const __m128i)
+    }
+
+    unsafe fn store(state: __m128i, bytes: &mut [Byte])
+    {
+        _mm_storeu_si128(bytes.as_mut_ptr() as *mut __m128i, state)
+    }
+
+    // Safety: the CPU must support AES-NI and data be a multiple of 16 bytes
+    #[target_feature(enable = "aes,sse2")]
+    pub unsafe fn encrypt(round_keys: &[[Byte; 16]], data: &mut [Byte])
+    {
+        let rounds = round_keys.len() - 1;
+        let keys = load_keys(round_keys);
+
+        for chunk in data.chunks_mut(16 * LANES)
+        {
+            let mut state = [_mm_setzero_si128(); LANES];
+            let lanes = chunk.len() / 16;
+
+            for i in 0..lanes { state[i] = _mm_xor_si128(load(&chunk[16*i..]), keys[0]); }
+
+            for r in 1..rounds
+            {
+                for i in 0..lanes { state[i] = _mm_aesenc_si128(state[i], keys[r]); }
+            }
+
+            for i in 0..lanes { store(_mm_aesenclast_si128(state[i], keys[rounds]), &mut chunk[16*i..]); }
+        }
+    }
+
+    // Safety: the CPU must support AES-NI and data be a multiple of 16 bytes
+    #[target_feature(enable = "aes,sse2")]
+    pub unsafe fn decrypt(round_keys: &[[Byte; 16]], data: &mut [Byte])
+    {
+        let rounds = round_keys.len() - 1;
+        let keys = load_keys(round_keys);
+
+        for chunk in data.chunks_mut(16 * LANES)
+        {
+            let mut state = [_mm_setzero_si128(); LANES];
+            let lanes = chunk.len() / 16;
+
+            for i in 0..lanes { state[i] = _mm_xor_si128(load(&chunk[16*i..]), keys[0]); }
+
+            for r in 1..rounds
+            {
+                for i in 0..lanes { state[i] = _mm_aesdec_si128(state[i], keys[r]); }
+            }
+
+            for i in 0..lanes { store(_mm_aesdeclast_si128(state[i], keys[rounds]), &mut chunk[16*i..]); }
+        }
+    }
+}
@@ -0,0 +1,513 @@
+use std::ptr;
+
+use crate::types::Byte;
+
+// Table-free AES, 8 blocks at a time: they are sliced into 8 planes, plane k holding bit k
+// of the 128 bytes, byte i of block b at bit 8 * i + b (so each byte position of the block
+// is a lane of 8 bits, row i % 4 of column i / 4)
+// Every operation is a fixed sequence of boolean ops, nothing depends on the data
+pub type Planes = [u128; 8];
+
+const BLOCKS: usize = 8;
+
+// 16-bit mask over the byte positions to the matching 8-bit lanes
+const fn lanes(mask: u16) -> u128
+{
+    let mut result = 0;
+    let mut i = 0;
+
+    while i < 16
+    {
+        if (mask >> i) & 1 == 1 { result |= 0xff << (8 * i); }
+        i += 1;
+    }
+
+    result
+}
+
+// Lanes of row r, then of rows r and up
+const ROWS: [u128; 4] = [lanes(0x1111), lanes(0x2222), lanes(0x4444), lanes(0x8888)];
+const FROM_ROW: [u128; 4] = [lanes(0xffff), lanes(0x7777), lanes(0x3333), lanes(0x1111)];
+
+// 8x8 bit matrix transpose, bit 8 * r + c goes to bit 8 * c + r (Hacker's Delight 7-3)
+fn transpose(x: u64) -> u64
+{
+    let mut x = x;
+
+    let t = (x ^ (x >> 7)) & 0x00aa00aa00aa00aa;
+    x ^= t ^ (t << 7);
+    let t = (x ^ (x >> 14)) & 0x0000cccc0000cccc;
+    x ^= t ^ (t << 14);
+    let t = (x ^ (x >> 28)) & 0x00000000f0f0f0f0;
+    x ^= t ^ (t << 28);
+
+    return x;
+}
+
+// Up to 8 blocks, the missing ones are zeros
+fn slice(blocks: &[Byte]) -> Planes
+{
+    let mut planes = [0; 8];
+
+    for i in 0..16
+    {
+        let mut column = [0; 8];
+
+        for b in 0..blocks.len() / 16
+        {
+            column[b] = blocks[16*b + i];
+        }
+
+        let bits = transpose(u64::from_le_bytes(column)).to_le_bytes();
+
+        for k in 0..8
+        {
+            planes[k] |= (bits[k] as u128) << (8 * i);
+        }
+    }
+
+    return planes;
+}
+
+fn unslice(planes: &Planes, blocks: &mut [Byte])
+{
+    for i in 0..16
+    {
+        let mut bits = [0; 8];
+
+        for k in 0..8
+        {
+            bits[k] = (planes[k] >> (8 * i)) as Byte;
+        }
+
+        let column = transpose(u64::from_le_bytes(bits)).to_le_bytes();
+
+        for b in 0..blocks.len() / 16
+        {
+            blocks[16*b + i] = column[b];
+        }
+    }
+}
+
+// Round keys sliced once, the same key in every lane
+pub fn slice_keys(round_keys: &[[Byte; 16]]) -> Vec<Planes>
+{
+    round_keys.iter()
+        .map( |key| slice(&key.repeat(BLOCKS)) )
+        .collect()
+}
+
+// Zeroes sliced round keys, see types::wipe
+pub fn wipe_keys(keys: &mut [Planes])
+{
+    for plane in keys.iter_mut().flat_map( |key| key.iter_mut() )
+    {
+        unsafe { ptr::write_volatile(plane, 0) };
+    }
+}
+
+fn add_round_key(q: &mut Planes, key: &Planes)
+{
+    for k in 0..8
+    {
+        q[k] ^= key[k];
+    }
+}
+
+// Boyar-Peralta circuit (2012, 113 gates) computing all 16 S-boxes at once
+fn sub_bytes(q: &mut Planes)
+{
+    let x0 = q[7];
+    let x1 = q[6];
+    let x2 = q[5];
+    let x3 = q[4];
+    let x4 = q[3];
+    let x5 = q[2];
+    let x6 = q[1];
+    let x7 = q[0];
+
+    // Top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9  = x0 ^ x3;
+    let y8  = x0 ^ x5;
+    let t0  = x1 ^ x2;
+    let y1  = t0 ^ x7;
+    let y4  = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2  = y1 ^ x0;
+    let y5  = y1 ^ x6;
+    let y3  = y5 ^ y8;
+    let t1  = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6  = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7  = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section, inversion in GF(2^8)
+    let t2  = y12 & y15;
+    let t3  = y3 & y6;
+    let t4  = t3 ^ t2;
+    let t5  = y4 & x7;
+    let t6  = t5 ^ t2;
+    let t7  = y13 & y16;
+    let t8  = y5 & y1;
+    let t9  = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0  = t44 & y15;
+    let z1  = t37 & y6;
+    let z2  = t33 & x7;
+    let z3  = t43 & y16;
+    let z4  = t40 & y1;
+    let z5  = t29 & y7;
+    let z6  = t42 & y11;
+    let z7  = t45 & y17;
+    let z8  = t41 & y10;
+    let z9  = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation, with the affine constant folded in the negations
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0  = t59 ^ t63;
+    let s6  = t56 ^ !t62;
+    let s7  = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3  = t53 ^ t66;
+    let s4  = t51 ^ t66;
+    let s5  = t47 ^ t65;
+    let s1  = t64 ^ !s3;
+    let s2  = t55 ^ !t67;
+
+    *q = [s7, s6, s5, s4, s3, s2, s1, s0];
+}
+
+// Inverse of the S-box affine map, x -> M^-1 (x + 0x63)
+fn inv_affine(q: &mut Planes)
+{
+    let q0 = !q[0];
+    let q1 = !q[1];
+    let q2 = q[2];
+    let q3 = q[3];
+    let q4 = q[4];
+    let q5 = !q[5];
+    let q6 = !q[6];
+    let q7 = q[7];
+
+    q[7] = q1 ^ q4 ^ q6;
+    q[6] = q0 ^ q3 ^ q5;
+    q[5] = q7 ^ q2 ^ q4;
+    q[4] = q6 ^ q1 ^ q3;
+    q[3] = q5 ^ q0 ^ q2;
+    q[2] = q4 ^ q7 ^ q1;
+    q[1] = q3 ^ q6 ^ q0;
+    q[0] = q2 ^ q5 ^ q7;
+}
+
+// S(x) = A(x^-1) so S^-1(x) = A^-1(S(A^-1(x)))
+fn inv_sub_bytes(q: &mut Planes)
+{
+    inv_affine(q);
+    sub_bytes(q);
+    inv_affine(q);
+}
+
+// Row r (lanes r, r + 4, r + 8, r + 12) rotated by r columns
+fn shift_rows(q: &mut Planes)
+{
+    for p in q.iter_mut()
+    {
+        *p = (*p & ROWS[0])
+           | (p.rotate_right(32) & ROWS[1])
+           | (p.rotate_right(64) & ROWS[2])
+           | (p.rotate_right(96) & ROWS[3]);
+    }
+}
+
+fn inv_shift_rows(q: &mut Planes)
+{
+    for p in q.iter_mut()
+    {
+        *p = (*p & ROWS[0])
+           | (p.rotate_left(32) & ROWS[1])
+           | (p.rotate_left(64) & ROWS[2])
+           | (p.rotate_left(96) & ROWS[3]);
+    }
+}
+
+// Row r + n of each column moved to row r
+fn rotate_rows(q: &Planes, n: u32) -> Planes
+{
+    let low = FROM_ROW[n as usize];
+
+    q.map( |p| ((p >> (8 * n)) & low) | ((p << (8 * (4 - n))) & !low) )
+}
+
+// Multiplication by x of every byte
+fn xtime(q: &Planes) -> Planes
+{
+    [q[7], q[0] ^ q[7], q[1], q[2] ^ q[7], q[3] ^ q[7], q[4], q[5], q[6]]
+}
+
+fn xor(a: &Planes, b: &Planes) -> Planes
+{
+    let mut result = *a;
+
+    for k in 0..8
+    {
+        result[k] ^= b[k];
+    }
+
+    return result;
+}
+
+// 2a + 3b + c + d = a + (a + b) * 2 + (a + b + c + d), as in the reference backend
+fn mix_columns(q: &mut Planes)
+{
+    let next = rotate_rows(q, 1);
+    let all  = xor(&xor(q, &next), &rotate_rows(&xor(q, &next), 2));
+
+    *q = xor(&xor(q, &all), &xtime(&xor(q, &next)));
+}
+
+fn inv_mix_columns(q: &mut Planes)
+{
+    let opposite = xor(q, &rotate_rows(q, 2));
+
+    *q = xor(q, &xtime(&xtime(&opposite)));
+
+    mix_columns(q);
+}
+
+fn encrypt(round_keys: &[Planes], q: &mut Planes)
+{
+    let rounds = round_keys.len() - 1;
+
+    add_round_key(q, &round_keys[0]);
+
+    for r in 1..rounds
+    {
+        sub_bytes(q);
+        shift_rows(q);
+        mix_columns(q);
+        add_round_key(q, &round_keys[r]);
+    }
+
+    sub_bytes(q);
+    shift_rows(q);
+    add_round_key(q, &round_keys[rounds]);
+}
+
+// Equivalent inverse cipher, round_keys must already be in decryption order
+fn decrypt(round_keys: &[Planes], q: &mut Planes)
+{
+    let rounds = round_keys.len() - 1;
+
+    add_round_key(q, &round_keys[0]);
+
+    for r in 1..rounds
+    {
+        inv_sub_bytes(q);
+        inv_shift_rows(q);
+        inv_mix_columns(q);
+        add_round_key(q, &round_keys[r]);
+    }
+
+    inv_sub_bytes(q);
+    inv_shift_rows(q);
+    add_round_key(q, &round_keys[rounds]);
+}
+
+// round_keys come from slice_keys, a lone block costs as much as 8
+pub fn encrypt_block(round_keys: &[Planes], block: &mut [Byte; 16])
+{
+    encrypt_blocks(round_keys, block);
+}
+
+pub fn decrypt_block(round_keys: &[Planes], block: &mut [Byte; 16])
+{
+    decrypt_blocks(round_keys, block);
+}
+
+// data must be a multiple of 16 bytes
+pub fn encrypt_blocks(round_keys: &[Planes], data: &mut [Byte])
+{
+    check(data);
+
+    for chunk in data.chunks_mut(16 * BLOCKS)
+    {
+        let mut q = slice(chunk);
+        encrypt(round_keys, &mut q);
+        unslice(&q, chunk);
+    }
+}
+
+pub fn decrypt_blocks(round_keys: &[Planes], data: &mut [Byte])
+{
+    check(data);
+
+    for chunk in data.chunks_mut(16 * BLOCKS)
+    {
+        let mut q = slice(chunk);
+        decrypt(round_keys, &mut q);
+        unslice(&q, chunk);
+    }
+}
+
+fn check(data: &[Byte])
+{
+    if !data.len().is_multiple_of(16) { panic!("Malformed data ({} is not a multiple of 16)", data.len()); }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::crypto::aes::consts::{SBOX, SBOX_INV};
+
+    // Every byte value, 128 at a time
+    fn apply(f: fn(&mut Planes)) -> Vec<Byte>
+    {
+        let mut result: Vec<Byte> = (0..=255).collect();
+
+        for chunk in result.chunks_mut(128)
+        {
+            let mut q = slice(chunk);
+            f(&mut q);
+            unslice(&q, chunk);
+        }
+
+        return result;
+    }
+
+    #[test]
+    fn sbox()
+    {
+        assert_eq!(SBOX.to_vec(), apply(sub_bytes));
+        assert_eq!(SBOX_INV.to_vec(), apply(inv_sub_bytes));
+    }
+
+    #[test]
+    fn slicing()
+    {
+        let blocks: Vec<Byte> = (0..128).map( |i| (i * 37 + 11) as Byte ).collect();
+        let mut output = vec![0; 128];
+
+        unslice(&super::slice(&blocks), &mut output);
+        assert_eq!(blocks, output);
+
+        // Bit k of byte i of block b is bit 8 * i + b of plane k
+        let q = super::slice(&blocks[..32]);
+        assert_eq!((blocks[16 + 5] >> 3) & 1, ((q[3] >> (8 * 5 + 1)) & 1) as Byte);
+        assert_eq!(0, q[0] >> (8 * 15 + 2));
+    }
+
+    #[test]
+    fn linear_layers()
+    {
+        let block = [0xd4, 0xbf, 0x5d, 0x30, 0xe0, 0xb4, 0x52, 0xae, 0xb8, 0x41, 0x11, 0xf1, 0x1e, 0x27, 0x98, 0xe5];
+        let mut q = super::slice(&block);
+        let mut output = [0; 16];
+
+        // FIPS 197 appendix B, round 1
+        mix_columns(&mut q);
+        unslice(&q, &mut output);
+        assert_eq!([0x04, 0x66, 0x81, 0xe5, 0xe0, 0xcb, 0x19, 0x9a, 0x48, 0xf8, 0xd3, 0x7a, 0x28, 0x06, 0x26, 0x4c], output);
+
+        inv_mix_columns(&mut q);
+        shift_rows(&mut q);
+        inv_shift_rows(&mut q);
+        unslice(&q, &mut output);
+        assert_eq!(block, output);
+    }
+
+    #[test]
+    fn lanes_are_independent()
+    {
+        let keys = slice_keys(&[[0x2b; 16]; 11]);
+        let mut data: Vec<Byte> = (0..16 * 11).map( |i| (i * 7) as Byte ).collect();
+        let mut one_by_one = data.clone();
+
+        encrypt_blocks(&keys, &mut data);
+
+        for block in one_by_one.chunks_mut(16)
+        {
+            encrypt_blocks(&keys, block);
+        }
+
+        assert_eq!(one_by_one, data);
+
+        decrypt_blocks(&slice_keys(&[[0x2b; 16]; 11]), &mut data);
+        assert_eq!((0..16 * 11).map( |i| (i * 7) as Byte ).collect::<Vec<Byte>>(), data);
+    }
+}
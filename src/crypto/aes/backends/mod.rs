@@ -0,0 +1,4 @@
+pub mod reference;
+pub mod ttable;
+pub mod bitsliced;
+pub mod aesni;
@@ -0,0 +1,124 @@
+use crate::types::Byte;
+use crate::crypto::aes::consts::{SBOX, SBOX_INV};
+
+// Byte-wise rounds as written in FIPS 197, kept to cross-check the other backends
+
+pub fn encrypt_block(round_keys: &[[Byte; 16]], block: &mut [Byte; 16])
+{
+    let rounds = round_keys.len() - 1;
+
+    add_round_key(block, &round_keys[0]);
+
+    for r in 1..rounds
+    {
+        sub_bytes(block, &SBOX);
+        shift_rows(block);
+        mix_columns(block);
+        add_round_key(block, &round_keys[r]);
+    }
+
+    sub_bytes(block, &SBOX);
+    shift_rows(block);
+    add_round_key(block, &round_keys[rounds]);
+}
+
+// Equivalent inverse cipher, round_keys must already be in decryption order
+pub fn decrypt_block(round_keys: &[[Byte; 16]], block: &mut [Byte; 16])
+{
+    let rounds = round_keys.len() - 1;
+
+    add_round_key(block, &round_keys[0]);
+
+    for r in 1..rounds
+    {
+        sub_bytes(block, &SBOX_INV);
+        inv_shift_rows(block);
+        inv_mix_columns(block);
+        add_round_key(block, &round_keys[r]);
+    }
+
+    sub_bytes(block, &SBOX_INV);
+    inv_shift_rows(block);
+    add_round_key(block, &round_keys[rounds]);
+}
+
+// Multiplication by x in GF(2^8), without branching on the value
+fn xtime(b: Byte) -> Byte
+{
+    (b << 1) ^ ((b >> 7) * 0x1b)
+}
+
+fn add_round_key(block: &mut [Byte; 16], round_key: &[Byte; 16])
+{
+    for i in 0..16
+    {
+        block[i] ^= round_key[i];
+    }
+}
+
+fn sub_bytes(block: &mut [Byte; 16], sbox: &[Byte; 256])
+{
+    for b in block.iter_mut()
+    {
+        *b = sbox[*b as usize];
+    }
+}
+
+// Row r is rotated left by r
+fn shift_rows(block: &mut [Byte; 16])
+{
+    let state = *block;
+
+    for c in 0..4
+    {
+        for r in 1..4
+        {
+            block[r + 4 * c] = state[r + 4 * ((c + r) % 4)];
+        }
+    }
+}
+
+fn inv_shift_rows(block: &mut [Byte; 16])
+{
+    let state = *block;
+
+    for c in 0..4
+    {
+        for r in 1..4
+        {
+            block[r + 4 * ((c + r) % 4)] = state[r + 4 * c];
+        }
+    }
+}
+
+fn mix_columns(block: &mut [Byte; 16])
+{
+    for column in block.chunks_exact_mut(4)
+    {
+        let (a0, a1, a2, a3) = (column[0], column[1], column[2], column[3]);
+        let all = a0 ^ a1 ^ a2 ^ a3;
+
+        // 2a + 3b + c + d = a + (a + b) * 2 + (a + b + c + d)
+        column[0] ^= all ^ xtime(a0 ^ a1);
+        column[1] ^= all ^ xtime(a1 ^ a2);
+        column[2] ^= all ^ xtime(a2 ^ a3);
+        column[3] ^= all ^ xtime(a3 ^ a0);
+    }
+}
+
+// InvMixColumns = MixColumns * (04x^2 + 05), see "The Design of Rijndael" 4.1.3
+pub fn inv_mix_columns(block: &mut [Byte; 16])
+{
+    for column in block.chunks_exact_mut(4)
+    {
+        let u = xtime(xtime(column[0] ^ column[2]));
+        let v = xtime(xtime(column[1] ^ column[3]));
+
+        column[0] ^= u;
+        column[1] ^= v;
+        column[2] ^= u;
+        column[3] ^= v;
+    }
+
+    mix_columns(block);
+}
@@ -0,0 +1,172 @@
+use crate::types::Byte;
+use crate::crypto::aes::consts::{SBOX, SBOX_INV};
+
+// Rounds as four lookups per column (Daemen & Rijmen, "AES Proposal: Rijndael" 5.2.1)
+// Columns are big-endian words, row 0 in the most significant byte
+// Table indices depend on the state, which is what makes this backend a cache-timing target
+
+// TE[0][x] = (2, 1, 1, 3) * S[x], TE[i] is TE[0] rotated right by 8i bits
+pub const TE: [[u32; 256]; 4] = tables(&SBOX, [2, 1, 1, 3]);
+
+// TD[0][x] = (e, 9, d, b) * Si[x], for the equivalent inverse cipher
+pub const TD: [[u32; 256]; 4] = tables(&SBOX_INV, [0x0e, 0x09, 0x0d, 0x0b]);
+
+const fn mul(a: u8, b: u8) -> u8
+{
+    let mut a = a;
+    let mut b = b;
+    let mut result = 0;
+
+    while b != 0
+    {
+        if b & 1 == 1 { result ^= a; }
+
+        a = (a << 1) ^ ((a >> 7) * 0x1b);
+        b >>= 1;
+    }
+
+    result
+}
+
+const fn tables(sbox: &[u8; 256], column: [u8; 4]) -> [[u32; 256]; 4]
+{
+    let mut tables = [[0; 256]; 4];
+    let mut x = 0;
+
+    while x < 256
+    {
+        let s = sbox[x];
+        let word = u32::from_be_bytes([mul(s, column[0]), mul(s, column[1]), mul(s, column[2]), mul(s, column[3])]);
+
+        tables[0][x] = word;
+        tables[1][x] = word.rotate_right(8);
+        tables[2][x] = word.rotate_right(16);
+        tables[3][x] = word.rotate_right(24);
+
+        x += 1;
+    }
+
+    tables
+}
+
+fn load(block: &[Byte; 16], round_key: &[Byte; 16]) -> [u32; 4]
+{
+    let mut state = [0; 4];
+
+    for c in 0..4
+    {
+        let column = [block[4*c], block[4*c + 1], block[4*c + 2], block[4*c + 3]];
+        let key    = [round_key[4*c], round_key[4*c + 1], round_key[4*c + 2], round_key[4*c + 3]];
+
+        state[c] = u32::from_be_bytes(column) ^ u32::from_be_bytes(key);
+    }
+
+    return state;
+}
+
+fn store(state: [u32; 4], block: &mut [Byte; 16])
+{
+    for c in 0..4
+    {
+        block[4*c..4*c + 4].copy_from_slice(&state[c].to_be_bytes());
+    }
+}
+
+fn key_word(round_key: &[Byte; 16], c: usize) -> u32
+{
+    u32::from_be_bytes([round_key[4*c], round_key[4*c + 1], round_key[4*c + 2], round_key[4*c + 3]])
+}
+
+fn byte(word: u32, row: usize) -> usize
+{
+    (word >> (24 - 8 * row)) as usize & 0xff
+}
+
+// One full round, row r of column c comes from column c + shift[r]
+fn round(tables: &[[u32; 256]; 4], state: [u32; 4], shift: [usize; 4], round_key: &[Byte; 16]) -> [u32; 4]
+{
+    let mut result = [0; 4];
+
+    for c in 0..4
+    {
+        result[c] = tables[0][byte(state[(c + shift[0]) % 4], 0)]
+                  ^ tables[1][byte(state[(c + shift[1]) % 4], 1)]
+                  ^ tables[2][byte(state[(c + shift[2]) % 4], 2)]
+                  ^ tables[3][byte(state[(c + shift[3]) % 4], 3)]
+                  ^ key_word(round_key, c);
+    }
+
+    return result;
+}
+
+// Last round has no MixColumns, plain S-box lookups
+fn last_round(sbox: &[u8; 256], state: [u32; 4], shift: [usize; 4], round_key: &[Byte; 16]) -> [u32; 4]
+{
+    let mut result = [0; 4];
+
+    for c in 0..4
+    {
+        let column = [
+            sbox[byte(state[(c + shift[0]) % 4], 0)],
+            sbox[byte(state[(c + shift[1]) % 4], 1)],
+            sbox[byte(state[(c + shift[2]) % 4], 2)],
+            sbox[byte(state[(c + shift[3]) % 4], 3)]
+        ];
+
+        result[c] = u32::from_be_bytes(column) ^ key_word(round_key, c);
+    }
+
+    return result;
+}
+
+// ShiftRows and InvShiftRows as column offsets per row
+const SHIFT: [usize; 4]     = [0, 1, 2, 3];
+const INV_SHIFT: [usize; 4] = [0, 3, 2, 1];
+
+pub fn encrypt_block(round_keys: &[[Byte; 16]], block: &mut [Byte; 16])
+{
+    let rounds = round_keys.len() - 1;
+    let mut state = load(block, &round_keys[0]);
+
+    for r in 1..rounds
+    {
+        state = round(&TE, state, SHIFT, &round_keys[r]);
+    }
+
+    store(last_round(&SBOX, state, SHIFT, &round_keys[rounds]), block);
+}
+
+// round_keys must be the equivalent inverse cipher schedule, in decryption order
+pub fn decrypt_block(round_keys: &[[Byte; 16]], block: &mut [Byte; 16])
+{
+    let rounds = round_keys.len() - 1;
+    let mut state = load(block, &round_keys[0]);
+
+    for r in 1..rounds
+    {
+        state = round(&TD, state, INV_SHIFT, &round_keys[r]);
+    }
+
+    store(last_round(&SBOX_INV, state, INV_SHIFT, &round_keys[rounds]), block);
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{TE, TD};
+
+    #[test]
+    fn tables()
+    {
+        // S[0] = 0x63, S[1] = 0x7c
+        assert_eq!(0xc66363a5, TE[0][0x00]);
+        assert_eq!(0xf87c7c84, TE[0][0x01]);
+        assert_eq!(0xa5c66363, TE[1][0x00]);
+        assert_eq!(0x63a5c663, TE[2][0x00]);
+        assert_eq!(0x6363a5c6, TE[3][0x00]);
+
+        // Si[0] = 0x52
+        assert_eq!(0x51f4a750, TD[0][0x00]);
+        assert_eq!(0x5051f4a7, TD[1][0x00]);
+    }
+}
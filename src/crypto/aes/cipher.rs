@@ -1,20 +1,29 @@
-use crate::lib::types::{Byte, ByteVec};
-use crate::lib::traits::BlockIterable;
+use crate::types::{Byte, Bytes};
 
 use super::types::{State, Context, Key};
 use super::traits::{Ops, KeyExpansion};
 
-pub fn encrypt(ctx: &Context, input: &ByteVec, key: &Key) -> ByteVec
+pub fn encrypt(ctx: &Context, input: &Bytes, key: &Key) -> Bytes
+{
+    encrypt_expanded(ctx, input, key.expand().expose())
+}
+
+pub fn decrypt(ctx: &Context, input: &[Byte], key: &Key) -> Bytes
+{
+    decrypt_expanded(ctx, input, key.expand().expose())
+}
+
+// With the key schedule already expanded
+pub fn encrypt_expanded(ctx: &Context, input: &[Byte], expanded: &[Byte]) -> Bytes
 {
     let mut state = State::from(ctx.block_size, input);
-    let kr_size = 4 * ctx.block_size;
 
-    let round_keys: Vec<ByteVec> = key.expand()
-        // Break expanded key into 16 bytes blocks
-        .blocks(4 * ctx.block_size)
+    // Break expanded key into blocks
+    let round_keys: Vec<&[Byte]> = expanded
+        .chunks(4 * ctx.block_size)
         .collect();
 
-    state.add_round_key(&round_keys[0]);
+    state.add_round_key(round_keys[0]);
 
     for r in 1..ctx.nb_rounds
     {
@@ -22,38 +31,38 @@ pub fn encrypt(ctx: &Context, input: &ByteVec, key: &Key) -> ByteVec
         state.shift_rows();
         state.mix_columns();
 
-        state.add_round_key(&round_keys[r]);
+        state.add_round_key(round_keys[r]);
     }
 
     state.sub_bytes();
     state.shift_rows();
-    state.add_round_key(&round_keys[ctx.nb_rounds]);
+    state.add_round_key(round_keys[ctx.nb_rounds]);
 
     state.output()
 }
 
-pub fn decrypt(ctx: &Context, input: &[Byte], key: &Key) -> ByteVec
+pub fn decrypt_expanded(ctx: &Context, input: &[Byte], expanded: &[Byte]) -> Bytes
 {
     let mut state = State::from(ctx.block_size, input);
 
-    let round_keys: Vec<ByteVec> = key.expand()
-        // Break expanded key into 16 bytes blocks
-        .blocks(4 * ctx.block_size)
+    // Break expanded key into blocks
+    let round_keys: Vec<&[Byte]> = expanded
+        .chunks(4 * ctx.block_size)
         .collect();
 
-    state.add_round_key(&round_keys[ctx.nb_rounds]);
+    state.add_round_key(round_keys[ctx.nb_rounds]);
 
     for r in (1..ctx.nb_rounds).rev()
     {
         state.inv_shift_rows();
         state.inv_sub_bytes();
-        state.add_round_key(&round_keys[r]);
+        state.add_round_key(round_keys[r]);
         state.inv_mix_columns();
     }
 
     state.inv_shift_rows();
     state.inv_sub_bytes();
-    state.add_round_key(&round_keys[0]);
+    state.add_round_key(round_keys[0]);
 
     state.output()
 }
@@ -63,8 +72,8 @@ mod tests
 {
     use super::*;
     use super::super::enums::AesType;
-    use crate::lib::types::ByteVec;
-    use crate::lib::traits::FromHex;
+    use crate::types::{Bytes, SecretBytes};
+    use crate::traits::FromHex;
 
     fn ctx() -> Context
     {
@@ -77,10 +86,10 @@ mod tests
         let ctx = ctx();
         let key = Key::from("YELLOW SUBMARINE");
 
-        let ciphertext = encrypt(&ctx, &ByteVec::from("Lorem ipsum dolo"), &key);
+        let ciphertext = encrypt(&ctx, &Bytes::from("Lorem ipsum dolo"), &key);
 
         assert_eq!(
-            ByteVec::from_hex("eadcc5aa4800dff175a49cf3a0f2041d"),
+            Bytes::from_hex("eadcc5aa4800dff175a49cf3a0f2041d"),
             ciphertext
         )
     }
@@ -89,12 +98,12 @@ mod tests
     fn uncipher()
     {
         let ctx = ctx();
-        let key = Key::new(&ByteVec::from_hex("000102030405060708090a0b0c0d0e0f"));
+        let key = Key::new(&Bytes::from_hex("000102030405060708090a0b0c0d0e0f"));
 
-        let plaintext = decrypt(&ctx, &ByteVec::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a"), &key);
+        let plaintext = decrypt(&ctx, &Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a"), &key);
 
         assert_eq!(
-            ByteVec::from_hex("00112233445566778899aabbccddeeff"),
+            Bytes::from_hex("00112233445566778899aabbccddeeff"),
             plaintext
         )
     }
@@ -104,11 +113,13 @@ mod tests
     {
         let ctx = ctx();
 
-        let plaintext = ByteVec::from_hex("00112233445566778899aabbccddeeff");
-        let key = Key::new(&ByteVec::from_hex("000102030405060708090a0b0c0d0e0f"));
+        let plaintext = Bytes::from_hex("00112233445566778899aabbccddeeff");
+        let key = Key::new(&Bytes::from_hex("000102030405060708090a0b0c0d0e0f"));
 
-        let round_keys: Vec<ByteVec> = key.expand()
-            .blocks(4 * ctx.block_size)
+        let round_keys: Vec<Bytes> = key.expand()
+            .expose()
+            .chunks(4 * ctx.block_size)
+            .map(Bytes::from)
             .collect();
 
         let expected = vec![
@@ -181,7 +192,7 @@ mod tests
         let mut state = State::from(ctx.block_size, &plaintext);
 
         assert_eq!(
-            ByteVec::from_hex("000102030405060708090a0b0c0d0e0f"),
+            Bytes::from_hex("000102030405060708090a0b0c0d0e0f"),
             round_keys[0].to_vec(),
             "round[0] .k_sch"
         );
@@ -191,7 +202,7 @@ mod tests
         for r in 1..ctx.nb_rounds
         {
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].0),
+                Bytes::from_hex(expected[r-1].0),
                 state.output(),
                 "round[{}] .start", r
             );
@@ -199,7 +210,7 @@ mod tests
             state.sub_bytes();
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].1),
+                Bytes::from_hex(expected[r-1].1),
                 state.output(),
                 "round[{}] .s_box", r
             );
@@ -207,7 +218,7 @@ mod tests
             state.shift_rows();
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].2),
+                Bytes::from_hex(expected[r-1].2),
                 state.output(),
                 "round[{}] .s_row", r
             );
@@ -215,7 +226,7 @@ mod tests
             state.mix_columns();
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].3),
+                Bytes::from_hex(expected[r-1].3),
                 state.output(),
                 "round[{}] .m_col", r
             );
@@ -223,14 +234,14 @@ mod tests
             state.add_round_key(&round_keys[r]);
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].4),
+                Bytes::from_hex(expected[r-1].4),
                 round_keys[r].to_vec(),
                 "round[{}] .k_sch", r
             );
         }
 
         assert_eq!(
-            ByteVec::from_hex("bd6e7c3df2b5779e0b61216e8b10b689"),
+            Bytes::from_hex("bd6e7c3df2b5779e0b61216e8b10b689"),
             state.output(),
             "round[10] .start"
         );
@@ -238,7 +249,7 @@ mod tests
         state.sub_bytes();
 
         assert_eq!(
-            ByteVec::from_hex("7a9f102789d5f50b2beffd9f3dca4ea7"),
+            Bytes::from_hex("7a9f102789d5f50b2beffd9f3dca4ea7"),
             state.output(),
             "round[10] .s_box"
         );
@@ -246,7 +257,7 @@ mod tests
         state.shift_rows();
 
         assert_eq!(
-            ByteVec::from_hex("7ad5fda789ef4e272bca100b3d9ff59f"),
+            Bytes::from_hex("7ad5fda789ef4e272bca100b3d9ff59f"),
             state.output(),
             "round[10] .s_row"
         );
@@ -254,13 +265,13 @@ mod tests
         state.add_round_key(&round_keys[ctx.nb_rounds]);
 
         assert_eq!(
-            ByteVec::from_hex("13111d7fe3944a17f307a78b4d2b30c5"),
+            Bytes::from_hex("13111d7fe3944a17f307a78b4d2b30c5"),
             round_keys[ctx.nb_rounds],
             "round[10] .k_sch"
         );
 
         assert_eq!(
-            ByteVec::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a"),
+            Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a"),
             state.output()
         );
     }
@@ -270,11 +281,13 @@ mod tests
     {
         let ctx = ctx();
 
-        let ciphertext = ByteVec::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a");
-        let key = Key::new(&ByteVec::from_hex("000102030405060708090a0b0c0d0e0f"));
+        let ciphertext = Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a");
+        let key = Key::new(&Bytes::from_hex("000102030405060708090a0b0c0d0e0f"));
 
-        let round_keys: Vec<ByteVec> = key.expand()
-            .blocks(4 * ctx.block_size)
+        let round_keys: Vec<Bytes> = key.expand()
+            .expose()
+            .chunks(4 * ctx.block_size)
+            .map(Bytes::from)
             .collect();
 
         let mut expected = vec![
@@ -348,7 +361,7 @@ mod tests
         let mut state = State::from(ctx.block_size, &ciphertext);
 
         assert_eq!(
-            ByteVec::from_hex("13111d7fe3944a17f307a78b4d2b30c5"),
+            Bytes::from_hex("13111d7fe3944a17f307a78b4d2b30c5"),
             round_keys[ctx.nb_rounds].to_vec(),
             "round[0] .ik_sch"
         );
@@ -358,7 +371,7 @@ mod tests
         for r in (1..ctx.nb_rounds).rev()
         {
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].0),
+                Bytes::from_hex(expected[r-1].0),
                 state.output(),
                 "round[{}] .istart", ctx.nb_rounds - r
             );
@@ -366,7 +379,7 @@ mod tests
             state.inv_shift_rows();
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].1),
+                Bytes::from_hex(expected[r-1].1),
                 state.output(),
                 "round[{}] .is_row", ctx.nb_rounds - r
             );
@@ -374,13 +387,13 @@ mod tests
             state.inv_sub_bytes();
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].2),
+                Bytes::from_hex(expected[r-1].2),
                 state.output(),
                 "round[{}] .is_box", ctx.nb_rounds - r
             );
             
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].3),
+                Bytes::from_hex(expected[r-1].3),
                 round_keys[r].to_vec(),
                 "round[{}] .ik_sch", ctx.nb_rounds - r
             );
@@ -388,7 +401,7 @@ mod tests
             state.add_round_key(&round_keys[r]);
 
             assert_eq!(
-                ByteVec::from_hex(expected[r-1].4),
+                Bytes::from_hex(expected[r-1].4),
                 state.output(),
                 "round[{}] .ik_add", ctx.nb_rounds - r
             );                
@@ -397,7 +410,7 @@ mod tests
         }
 
         assert_eq!(
-            ByteVec::from_hex("6353e08c0960e104cd70b751bacad0e7"),
+            Bytes::from_hex("6353e08c0960e104cd70b751bacad0e7"),
             state.output(),
             "round[10] .istart"
         );
@@ -405,7 +418,7 @@ mod tests
         state.inv_shift_rows();
 
         assert_eq!(
-            ByteVec::from_hex("63cab7040953d051cd60e0e7ba70e18c"),
+            Bytes::from_hex("63cab7040953d051cd60e0e7ba70e18c"),
             state.output(),
             "round[10] .is_row"
         );
@@ -413,13 +426,13 @@ mod tests
         state.inv_sub_bytes();
 
         assert_eq!(
-            ByteVec::from_hex("00102030405060708090a0b0c0d0e0f0"),
+            Bytes::from_hex("00102030405060708090a0b0c0d0e0f0"),
             state.output(),
             "round[10] .is_box"
         );
 
         assert_eq!(
-            ByteVec::from_hex("000102030405060708090a0b0c0d0e0f"),
+            Bytes::from_hex("000102030405060708090a0b0c0d0e0f"),
             round_keys[0],
             "round[10] .ik_sch"
         );
@@ -427,8 +440,54 @@ mod tests
         state.add_round_key(&round_keys[0]);
 
         assert_eq!(
-            ByteVec::from_hex("00112233445566778899aabbccddeeff"),
+            Bytes::from_hex("00112233445566778899aabbccddeeff"),
             state.output()
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn rijndael()
+    {
+        // Brian Gladman's test vectors, (Nb, Nk, ciphertext)
+        let key       = Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfe");
+        let plaintext = Bytes::from_hex("3243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c8");
+
+        let vectors = [
+            (4, 4, "3925841d02dc09fbdc118597196a0b32"),
+            (4, 5, "231d844639b31b412211cfe93712b880"),
+            (4, 6, "f9fb29aefc384a250340d833b87ebc00"),
+            (4, 7, "8faa8fe4dee9eb17caa4797502fc9d3f"),
+            (4, 8, "1a6e6c2c662e7da6501ffb62bc9e93f3"),
+            (5, 4, "16e73aec921314c29df905432bc8968ab64b1f51"),
+            (5, 5, "0553eb691670dd8a5a5b5addf1aa7450f7a0e587"),
+            (5, 6, "73cd6f3423036790463aa9e19cfcde894ea16623"),
+            (5, 7, "601b5dcd1cf4ece954c740445340bf0afdc048df"),
+            (5, 8, "579e930b36c1529aa3e86628bacfe146942882cf"),
+            (6, 4, "b24d275489e82bb8f7375e0d5fcdb1f481757c538b65148a"),
+            (6, 5, "738dae25620d3d3beff4a037a04290d73eb33521a63ea568"),
+            (6, 6, "725ae43b5f3161de806a7c93e0bca93c967ec1ae1b71e1cf"),
+            (6, 7, "bbfc14180afbf6a36382a061843f0b63e769acdc98769130"),
+            (6, 8, "0ebacf199e3315c2e34b24fcc7c46ef4388aa475d66c194c"),
+            (7, 4, "b0a8f78f6b3c66213f792ffd2a61631f79331407a5e5c8d3793aceb1"),
+            (7, 5, "08b99944edfce33a2acb131183ab0168446b2d15e958480010f545e3"),
+            (7, 6, "be4c597d8f7efe22a2f7e5b1938e2564d452a5bfe72399c7af1101e2"),
+            (7, 7, "ef529598ecbce297811b49bbed2c33bbe1241d6e1a833dbe119569e8"),
+            (7, 8, "02fafc200176ed05deb8edb82a3555b0b10d47a388dfd59cab2f6c11"),
+            (8, 4, "7d15479076b69a46ffb3b3beae97ad8313f622f67fedb487de9f06b9ed9c8f19"),
+            (8, 5, "514f93fb296b5ad16aa7df8b577abcbd484decacccc7fb1f18dc567309ceeffd"),
+            (8, 6, "5d7101727bb25781bf6715b0e6955282b9610e23a43c2eb062699f0ebf5887b2"),
+            (8, 7, "d56c5a63627432579e1dd308b2c8f157b40a4bfb56fea1377b25d3ed3d6dbf80"),
+            (8, 8, "a49406115dfb30a40418aafa4869b7c6a886ff31602a7dd19c889dc64f7e4e7a")
+        ];
+
+        for (nb, nk, expected) in vectors.iter()
+        {
+            let key = Key::rijndael(SecretBytes::new(&key[..4 * nk]), *nb);
+            let ctx = Context::from_key(&key);
+            let ciphertext = encrypt(&ctx, &Bytes::from(&plaintext[..4 * nb]), &key);
+
+            assert_eq!(Bytes::from_hex(expected), ciphertext, "Nb = {}, Nk = {}", nb, nk);
+            assert_eq!(&plaintext[..4 * nb], &decrypt(&ctx, &ciphertext, &key)[..]);
+        }
+    }
+}
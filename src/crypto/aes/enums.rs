@@ -0,0 +1,63 @@
+use std::fmt;
+
+use super::backends::aesni;
+
+pub enum AesType
+{
+    Aes128,
+    Aes192,
+    Aes256
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AesMode
+{
+    ECB,
+    CBC,
+    CTR,
+    CFB1,
+    CFB8,
+    CFB128,
+    OFB
+}
+
+// Block implementation used by Aes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend
+{
+    // Byte-wise FIPS 197 rounds
+    Reference,
+    // Te0..Te3 / Td0..Td3 lookups, fast but leaks through cache timing
+    TTable,
+    // Table-free and branch-free blocks, for use on secret data
+    // (the key schedule still goes through the S-box table, once per key)
+    Bitsliced,
+    // x86_64 AES instructions, falls back to Bitsliced when the CPU lacks them
+    AesNi
+}
+
+impl Backend
+{
+    // Fastest constant-time backend on this CPU, TTable is only used when asked for
+    pub fn detect() -> Backend
+    {
+        if aesni::is_available() { Backend::AesNi } else { Backend::Bitsliced }
+    }
+}
+
+impl fmt::Display for AesMode
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    { 
+        match self
+        {
+            Self::ECB    => write!(f, "ECB"),
+            Self::CBC    => write!(f, "CBC"),
+            Self::CTR    => write!(f, "CTR"),
+            Self::CFB1   => write!(f, "CFB1"),
+            Self::CFB8   => write!(f, "CFB8"),
+            Self::CFB128 => write!(f, "CFB128"),
+            Self::OFB    => write!(f, "OFB")
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,254 @@
+pub mod consts;
+pub mod traits;
+pub mod cipher;
+pub mod backends;
+
+mod types;
+mod enums;
+
+pub use types::Aes;
+pub use types::Key;
+pub use types::Context;
+pub use types::Message;
+pub use types::Rijndael;
+
+pub use enums::{AesType, AesMode, Backend};
+
+use crate::types::{Bytes, SecretBytes};
+use crate::crypto::BlockCipher;
+use crate::crypto::modes::{Mode, Ecb, Cbc, Ctr, Cfb, Ofb, Segment, Gcm, Siv, GcmSiv};
+
+pub fn encrypt(mode: AesMode, message: &Message) -> Bytes
+{
+    let cipher = block_cipher(&message.key);
+
+    match mode
+    {
+        AesMode::ECB    => Ecb::new(cipher).encrypt(&message.content),
+        AesMode::CBC    => Cbc::new(cipher, &message.iv()).encrypt(&message.content),
+        AesMode::CTR    => Ctr::new(cipher, &message.nonce(), message.counter_layout).encrypt(&message.content),
+        AesMode::CFB1   => Cfb::new(cipher, &message.iv(), Segment::Bit).encrypt(&message.content),
+        AesMode::CFB8   => Cfb::new(cipher, &message.iv(), Segment::Byte).encrypt(&message.content),
+        AesMode::CFB128 => Cfb::new(cipher, &message.iv(), Segment::Block).encrypt(&message.content),
+        AesMode::OFB    => Ofb::new(cipher, &message.iv()).encrypt(&message.content)
+    }
+}
+
+pub fn decrypt(mode: AesMode, message: &Message) -> Bytes
+{
+    let cipher = block_cipher(&message.key);
+
+    match mode
+    {
+        AesMode::ECB    => Ecb::new(cipher).decrypt(&message.content),
+        AesMode::CBC    => Cbc::new(cipher, &message.iv()).decrypt(&message.content),
+        AesMode::CTR    => Ctr::new(cipher, &message.nonce(), message.counter_layout).decrypt(&message.content),
+        AesMode::CFB1   => Cfb::new(cipher, &message.iv(), Segment::Bit).decrypt(&message.content),
+        AesMode::CFB8   => Cfb::new(cipher, &message.iv(), Segment::Byte).decrypt(&message.content),
+        AesMode::CFB128 => Cfb::new(cipher, &message.iv(), Segment::Block).decrypt(&message.content),
+        AesMode::OFB    => Ofb::new(cipher, &message.iv()).decrypt(&message.content)
+    }
+}
+
+// CTR keystream for the message key and nonce, for seeking and editing in place
+pub fn ctr(message: &Message) -> Ctr<Box<dyn BlockCipher>>
+{
+    Ctr::new(block_cipher(&message.key), &message.nonce(), message.counter_layout)
+}
+
+// AEAD with 128-bit tags, see Gcm::with_tag_size to truncate them
+pub fn gcm(key: &Key) -> Gcm<Aes>
+{
+    Gcm::new(Aes::new(key))
+}
+
+// AES-128 or AES-256 key-generating key, the per-nonce keys use the same backend
+pub fn gcm_siv(key: &Key) -> GcmSiv<Aes>
+{
+    GcmSiv::new(Aes::new(key))
+}
+
+// K1 || K2 of 32, 48 or 64 bytes, K1 for S2V and K2 for CTR
+pub fn siv(key: &SecretBytes) -> Siv<Aes>
+{
+    if ![32, 48, 64].contains(&key.len())
+    {
+        panic!("Malformed key (AES-SIV keys are 256, 384 or 512 bits)");
+    }
+
+    let (k1, k2) = key.expose().split_at(key.len() / 2);
+
+    Siv::new(Aes::new(&Key::new(k1)), Aes::new(&Key::new(k2)))
+}
+
+// Aes for 128-bit blocks, the reference rounds for wider Rijndael blocks
+fn block_cipher(key: &Key) -> Box<dyn BlockCipher>
+{
+    match key.block_size()
+    {
+        4 => Box::new(Aes::new(key)),
+        _ => Box::new(Rijndael::new(key))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::traits::FromHex;
+    use crate::types::SecretBytes;
+    use crate::crypto::modes::CounterLayout;
+
+    #[test]
+    fn encrypt_ebc()
+    {
+        let msg = Message::from(
+                            Bytes::from_hex("00112233445566778899AABBCCDDEEFF"),
+                            Key::new(&Bytes::from_hex("000102030405060708090A0B0C0D0E0F"))
+                        );
+
+        let expected = Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a954f64f2e4e86e9eee82d20216684899");
+
+        assert_eq!(
+            expected,
+            super::encrypt(AesMode::ECB, &msg)
+        );
+    }
+
+    #[test]
+    fn decrypt_ebc()
+    {
+        let msg = Message::from(
+                            Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a954f64f2e4e86e9eee82d20216684899"),
+                            Key::new(&Bytes::from_hex("000102030405060708090A0B0C0D0E0F"))
+                        );
+
+        let expected = Bytes::from_hex("00112233445566778899AABBCCDDEEFF");
+
+        assert_eq!(
+            expected,
+            super::decrypt(AesMode::ECB, &msg)
+        );
+    }
+
+    #[test]
+    fn encrypt_cbc()
+    {
+        let msg = Message::from(
+                            Bytes::from_hex("00112233445566778899AABBCCDDEEFF"),
+                            Key::new(&Bytes::from_hex("000102030405060708090A0B0C0D0E0F"))
+                        )
+                        .with_iv(Bytes::from([0x0; 16]));
+
+        let expected = Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a9e978e6d16b086570ef794ef97984232");
+
+        assert_eq!(
+            expected,
+            super::encrypt(AesMode::CBC, &msg)
+        );
+    }
+
+    #[test]
+    fn decrypt_cbc()
+    {
+        let msg = Message::from(
+                            Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a9e978e6d16b086570ef794ef97984232"),
+                            Key::new(&Bytes::from_hex("000102030405060708090A0B0C0D0E0F"))
+                        )
+                        .with_iv(Bytes::from([0x0; 16]));
+
+        let expected = Bytes::from_hex("00112233445566778899AABBCCDDEEFF");
+
+        assert_eq!(
+            expected,
+            super::decrypt(AesMode::CBC, &msg)
+        );
+    }
+
+    #[test]
+    fn rijndael_modes()
+    {
+        let plaintext = Bytes::from("Rijndael with 256-bit blocks, padded with PKCS#7");
+
+        for mode in [AesMode::ECB, AesMode::CBC]
+        {
+            let key = Key::rijndael(SecretBytes::new(&[0x2b; 20]), 8);
+            let msg = Message::from(plaintext.clone(), key).with_iv(Bytes::from([0x0; 32]));
+
+            let ciphertext = encrypt(mode, &msg);
+            assert_eq!(64, ciphertext.len());
+
+            let msg = Message::from(ciphertext, Key::rijndael(SecretBytes::new(&[0x2b; 20]), 8))
+                .with_iv(Bytes::from([0x0; 32]));
+
+            assert_eq!(plaintext, decrypt(mode, &msg));
+        }
+    }
+
+    #[test]
+    fn ctr_message()
+    {
+        let msg = Message::from(Bytes::from("No padding in CTR"), Key::from("YELLOW SUBMARINE"))
+            .with_nonce(Bytes::from([0x0; 8]));
+
+        let ciphertext = encrypt(AesMode::CTR, &msg);
+        assert_eq!(17, ciphertext.len());
+
+        let msg = Message::from(ciphertext, Key::from("YELLOW SUBMARINE"))
+            .with_nonce(Bytes::from([0x0; 8]));
+
+        assert_eq!(Bytes::from("No padding in CTR"), decrypt(AesMode::CTR, &msg));
+    }
+
+    #[test]
+    fn ctr_nist()
+    {
+        // SP 800-38A F.5.1, first block
+        let msg = Message::from(
+                            Bytes::from_hex("6bc1bee22e409f96e93d7e117393172a"),
+                            Key::new(&Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c"))
+                        )
+                        .with_nonce(Bytes::from_hex("f0f1f2f3f4f5f6f7f8f9fafb"))
+                        .with_counter_layout(CounterLayout::NIST);
+
+        let expected = Bytes::from_hex("874d6191b620e3261bef6864990db6ce");
+
+        assert_eq!(expected, ctr(&msg).with_counter(0xfcfdfeff).encrypt(&msg.content));
+    }
+
+    #[test]
+    fn stream_modes()
+    {
+        // SP 800-38A F.3.2, F.3.8, F.3.14 and F.4.2, first block
+        let cases = [
+            (AesMode::CFB1,   "6bc1",                             "68b3"),
+            (AesMode::CFB8,   "6bc1bee22e409f96e93d7e117393172a", "3b79424c9c0dd436bace9e0ed4586a4f"),
+            (AesMode::CFB128, "6bc1bee22e409f96e93d7e117393172a", "3b3fd92eb72dad20333449f8e83cfb4a"),
+            (AesMode::OFB,    "6bc1bee22e409f96e93d7e117393172a", "3b3fd92eb72dad20333449f8e83cfb4a")
+        ];
+
+        for (mode, plaintext, ciphertext) in cases
+        {
+            let msg = Message::from(
+                                Bytes::from_hex(ciphertext),
+                                Key::new(&Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c"))
+                            )
+                            .with_iv(Bytes::from_hex("000102030405060708090a0b0c0d0e0f"));
+
+            assert_eq!(Bytes::from_hex(plaintext), decrypt(mode, &msg), "{}", mode);
+        }
+    }
+
+    #[test]
+    fn siv_key()
+    {
+        // RFC 5297 A.1
+        let key = SecretBytes::new(&Bytes::from_hex("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"));
+        let aad = Bytes::from_hex("101112131415161718191a1b1c1d1e1f2021222324252627");
+
+        let (ciphertext, v) = siv(&key).seal(&[&aad], &Bytes::from_hex("112233445566778899aabbccddee"));
+
+        assert_eq!(Bytes::from_hex("85632d07c6e8f37f950acd320a2ecc93"), v);
+        assert_eq!(Bytes::from_hex("40c02b9690c4dc04daef7f6afe5c"), ciphertext);
+    }
+}
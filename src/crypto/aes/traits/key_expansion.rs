@@ -0,0 +1,6 @@
+use crate::types::SecretBytes;
+
+pub trait KeyExpansion
+{
+    fn expand(&self) -> SecretBytes;
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::lib::types::Byte;
+use crate::types::Byte;
 
 pub trait Ops
 {
@@ -0,0 +1,389 @@
+use std::convert::TryInto;
+use std::fmt;
+
+use crate::types::{Byte, SecretBytes, wipe};
+use crate::crypto::{BlockCipher, Rekey};
+use crate::crypto::aes::{Key, Backend};
+use crate::crypto::aes::backends::{reference, ttable, bitsliced, aesni};
+use crate::crypto::aes::traits::KeyExpansion;
+
+const MAX_ROUNDS: usize = 14;
+
+// AES with the key schedule expanded once, blocks are processed in place
+// (column-major, byte i is row i % 4 of column i / 4, as in FIPS 197)
+pub struct Aes
+{
+    backend:      Backend,
+    rounds:       usize,
+    encrypt_keys: [[Byte; 16]; MAX_ROUNDS + 1],
+    // Equivalent inverse cipher (FIPS 197, 5.3.5), already in decryption order
+    decrypt_keys: [[Byte; 16]; MAX_ROUNDS + 1],
+    // Both schedules sliced once for the bitsliced backend, empty otherwise
+    sliced_encrypt_keys: Vec<bitsliced::Planes>,
+    sliced_decrypt_keys: Vec<bitsliced::Planes>
+}
+
+impl Aes
+{
+    pub const BLOCK_SIZE: usize = 16;
+
+    // Picks the fastest constant-time backend available
+    pub fn new(key: &Key) -> Aes
+    {
+        Aes::with_backend(key, Backend::detect())
+    }
+
+    pub fn with_backend(key: &Key, backend: Backend) -> Aes
+    {
+        if key.block_size() != 4 { panic!("Malformed key (AES blocks are 128 bits, use cipher for Rijndael)"); }
+
+        let backend = match backend
+        {
+            Backend::AesNi if !aesni::is_available() => Backend::Bitsliced,
+            backend => backend
+        };
+
+        let expanded = key.expand();
+        let rounds   = expanded.len() / Self::BLOCK_SIZE - 1;
+
+        let mut aes = Aes {
+            backend:      backend,
+            rounds:       rounds,
+            encrypt_keys: [[0; 16]; MAX_ROUNDS + 1],
+            decrypt_keys: [[0; 16]; MAX_ROUNDS + 1],
+
+            sliced_encrypt_keys: Vec::new(),
+            sliced_decrypt_keys: Vec::new()
+        };
+
+        for (i, round_key) in expanded.expose().chunks(Self::BLOCK_SIZE).enumerate()
+        {
+            aes.encrypt_keys[i].copy_from_slice(round_key);
+        }
+
+        aes.decrypt_keys[0] = aes.encrypt_keys[rounds];
+        aes.decrypt_keys[rounds] = aes.encrypt_keys[0];
+
+        for r in 1..rounds
+        {
+            aes.decrypt_keys[r] = aes.encrypt_keys[rounds - r];
+            reference::inv_mix_columns(&mut aes.decrypt_keys[r]);
+        }
+
+        if backend == Backend::Bitsliced
+        {
+            aes.sliced_encrypt_keys = bitsliced::slice_keys(&aes.encrypt_keys[..=rounds]);
+            aes.sliced_decrypt_keys = bitsliced::slice_keys(&aes.decrypt_keys[..=rounds]);
+        }
+
+        return aes;
+    }
+
+    pub fn rounds(&self) -> usize
+    {
+        self.rounds
+    }
+
+    pub fn backend(&self) -> Backend
+    {
+        self.backend
+    }
+
+    pub fn encrypt_block(&self, block: &mut [Byte; 16])
+    {
+        let round_keys = &self.encrypt_keys[..=self.rounds];
+
+        match self.backend
+        {
+            Backend::Reference => reference::encrypt_block(round_keys, block),
+            Backend::TTable    => ttable::encrypt_block(round_keys, block),
+            Backend::Bitsliced => bitsliced::encrypt_block(&self.sliced_encrypt_keys, block),
+            Backend::AesNi     => aesni::encrypt_block(round_keys, block)
+        }
+    }
+
+    pub fn decrypt_block(&self, block: &mut [Byte; 16])
+    {
+        let round_keys = &self.decrypt_keys[..=self.rounds];
+
+        match self.backend
+        {
+            Backend::Reference => reference::decrypt_block(round_keys, block),
+            Backend::TTable    => ttable::decrypt_block(round_keys, block),
+            Backend::Bitsliced => bitsliced::decrypt_block(&self.sliced_decrypt_keys, block),
+            Backend::AesNi     => aesni::decrypt_block(round_keys, block)
+        }
+    }
+
+    // Every block of data, which must be a multiple of the block size
+    pub fn encrypt_blocks(&self, data: &mut [Byte])
+    {
+        match self.backend
+        {
+            Backend::AesNi     => aesni::encrypt_blocks(&self.encrypt_keys[..=self.rounds], data),
+            Backend::Bitsliced => bitsliced::encrypt_blocks(&self.sliced_encrypt_keys, data),
+            _ => for block in blocks_mut(data) { self.encrypt_block(block); }
+        }
+    }
+
+    pub fn decrypt_blocks(&self, data: &mut [Byte])
+    {
+        match self.backend
+        {
+            Backend::AesNi     => aesni::decrypt_blocks(&self.decrypt_keys[..=self.rounds], data),
+            Backend::Bitsliced => bitsliced::decrypt_blocks(&self.sliced_decrypt_keys, data),
+            _ => for block in blocks_mut(data) { self.decrypt_block(block); }
+        }
+    }
+}
+
+impl BlockCipher for Aes
+{
+    fn block_size(&self) -> usize
+    {
+        Self::BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &mut [Byte])
+    {
+        Aes::encrypt_block(self, as_block(block));
+    }
+
+    fn decrypt_block(&self, block: &mut [Byte])
+    {
+        Aes::decrypt_block(self, as_block(block));
+    }
+
+    fn encrypt_blocks(&self, data: &mut [Byte])
+    {
+        Aes::encrypt_blocks(self, data);
+    }
+
+    fn decrypt_blocks(&self, data: &mut [Byte])
+    {
+        Aes::decrypt_blocks(self, data);
+    }
+}
+
+// Same backend under the new key
+impl Rekey for Aes
+{
+    fn key_size(&self) -> usize
+    {
+        4 * (self.rounds - 6)
+    }
+
+    fn rekey(&self, key: &SecretBytes) -> Aes
+    {
+        Aes::with_backend(&Key::from_secret(key.clone()), self.backend)
+    }
+}
+
+fn as_block(block: &mut [Byte]) -> &mut [Byte; 16]
+{
+    let length = block.len();
+
+    match block.try_into()
+    {
+        Ok(block) => block,
+        Err(_)    => panic!("Malformed block ({} != 16)", length)
+    }
+}
+
+impl Drop for Aes
+{
+    fn drop(&mut self)
+    {
+        for round_key in self.encrypt_keys.iter_mut().chain(self.decrypt_keys.iter_mut())
+        {
+            wipe(round_key);
+        }
+
+        bitsliced::wipe_keys(&mut self.sliced_encrypt_keys);
+        bitsliced::wipe_keys(&mut self.sliced_decrypt_keys);
+    }
+}
+
+impl fmt::Debug for Aes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "Aes {{ backend: {:?}, rounds: {} }}", self.backend, self.rounds)
+    }
+}
+
+// Panics if data is not a multiple of the block size
+fn blocks_mut(data: &mut [Byte]) -> impl Iterator<Item = &mut [Byte; 16]>
+{
+    if !data.len().is_multiple_of(Aes::BLOCK_SIZE)
+    {
+        panic!("Malformed data ({} is not a multiple of {})", data.len(), Aes::BLOCK_SIZE);
+    }
+
+    data.chunks_exact_mut(Aes::BLOCK_SIZE).map( |block| block.try_into().unwrap() )
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{Aes, aesni};
+    use crate::crypto::Rekey;
+    use crate::crypto::aes::{Key, Backend, Context, cipher};
+    use crate::types::{Bytes, SecretBytes};
+    use crate::traits::{FromHex, Random};
+
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+
+    const BACKENDS: [Backend; 4] = [Backend::Reference, Backend::TTable, Backend::Bitsliced, Backend::AesNi];
+
+    fn block(hex: &str) -> [u8; 16]
+    {
+        let mut block = [0; 16];
+        block.copy_from_slice(&Bytes::from_hex(hex));
+
+        return block;
+    }
+
+    #[test]
+    fn fips_197()
+    {
+        // Appendix C
+        let cases = [
+            ("000102030405060708090a0b0c0d0e0f", "69c4e0d86a7b0430d8cdb78070b4c55a"),
+            ("000102030405060708090a0b0c0d0e0f1011121314151617", "dda97ca4864cdfe06eaf70a0ec0d7191"),
+            ("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f", "8ea2b7ca516745bfeafc49904b496089")
+        ];
+
+        for (key, expected) in cases.iter()
+        {
+            for backend in BACKENDS.iter()
+            {
+                let aes = Aes::with_backend(&Key::new(&Bytes::from_hex(key)), *backend);
+                let mut data = block("00112233445566778899aabbccddeeff");
+
+                aes.encrypt_block(&mut data);
+                assert_eq!(block(expected), data, "{:?}", backend);
+
+                aes.decrypt_block(&mut data);
+                assert_eq!(block("00112233445566778899aabbccddeeff"), data, "{:?}", backend);
+            }
+        }
+    }
+
+    #[test]
+    fn backends_agree()
+    {
+        let mut rng = StdRng::seed_from_u64(197);
+
+        for size in [16, 24, 32].iter()
+        {
+            let key       = Key::random(*size, &mut rng);
+            let reference = Aes::with_backend(&key, Backend::Reference);
+
+            for backend in BACKENDS.iter()
+            {
+                let aes = Aes::with_backend(&key, *backend);
+
+                for _ in 0..64
+                {
+                    let mut expected = [0; 16];
+                    rng.fill(&mut expected);
+
+                    let mut data = expected;
+
+                    reference.encrypt_block(&mut expected);
+                    aes.encrypt_block(&mut data);
+                    assert_eq!(expected, data, "{:?}", backend);
+
+                    reference.decrypt_block(&mut expected);
+                    aes.decrypt_block(&mut data);
+                    assert_eq!(expected, data, "{:?}", backend);
+                }
+            }
+        }
+
+        assert_eq!(Backend::TTable, Aes::with_backend(&Key::random(16, &mut rng), Backend::TTable).backend());
+    }
+
+    #[test]
+    fn cipher_path()
+    {
+        let mut rng = StdRng::seed_from_u64(19);
+
+        for size in [16, 24, 32].iter()
+        {
+            let key = Key::random(*size, &mut rng);
+            let ctx = Context::from_key(&key);
+            let plaintext = Bytes::random(16 * 9, &mut rng);
+
+            let expected: Bytes = plaintext.chunks(16)
+                .flat_map( |block| cipher::encrypt(&ctx, &Bytes::from(block), &key) )
+                .collect();
+
+            for backend in BACKENDS.iter()
+            {
+                let aes = Aes::with_backend(&key, *backend);
+                let mut data = plaintext.clone();
+
+                aes.encrypt_blocks(&mut data);
+                assert_eq!(expected, data, "{:?}", aes.backend());
+
+                aes.decrypt_blocks(&mut data);
+                assert_eq!(plaintext, data, "{:?}", aes.backend());
+            }
+        }
+    }
+
+    #[test]
+    fn detect()
+    {
+        let key = Key::from("YELLOW SUBMARINE");
+
+        assert_eq!(Backend::detect(), Aes::new(&key).backend());
+
+        match aesni::is_available()
+        {
+            true  => assert_eq!(Backend::AesNi, Aes::with_backend(&key, Backend::AesNi).backend()),
+            false => assert_eq!(Backend::Bitsliced, Aes::with_backend(&key, Backend::AesNi).backend())
+        }
+
+        // Never the cache-timing leaky tables unless asked for
+        assert!([Backend::AesNi, Backend::Bitsliced].contains(&Backend::detect()));
+    }
+
+    #[test]
+    fn rekey()
+    {
+        let aes = Aes::with_backend(&Key::from("YELLOW SUBMARINE"), Backend::Reference);
+        let other = aes.rekey(&SecretBytes::new(&[0x2a; 32]));
+
+        assert_eq!(16, aes.key_size());
+        assert_eq!(32, other.key_size());
+        assert_eq!(Backend::Reference, other.backend());
+    }
+
+    #[test]
+    fn blocks()
+    {
+        let aes = Aes::new(&Key::from("YELLOW SUBMARINE"));
+        let plaintext = Bytes::from("Lorem ipsum dolor sit amet, cons");
+        let mut data = plaintext.clone();
+
+        aes.encrypt_blocks(&mut data);
+
+        assert_ne!(plaintext, data);
+
+        aes.decrypt_blocks(&mut data);
+
+        assert_eq!(plaintext, data);
+        assert_eq!(10, aes.rounds());
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed data")]
+    fn blocks_malformed()
+    {
+        Aes::new(&Key::from("YELLOW SUBMARINE")).encrypt_blocks(&mut [0; 15]);
+    }
+}
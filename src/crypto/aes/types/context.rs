@@ -1,5 +1,5 @@
-use crate::lib::crypto::aes::enums::AesType;
-use crate::lib::crypto::aes::Key;
+use crate::crypto::aes::enums::AesType;
+use crate::crypto::aes::Key;
 
 // TODO: should protect fields private/getter ?
 pub struct Context
@@ -23,12 +23,10 @@ impl Context
 
     pub fn from_key(key: &Key) -> Self
     {
-        match key.length()
-        {
-            4 => Self::new(AesType::Aes128),
-            6 => Self::new(AesType::Aes192),
-            8 => Self::new(AesType::Aes256),
-            _ => panic!("Malformed key")
+        Context {
+            key_length: key.length(),
+            block_size: key.block_size(),
+            nb_rounds:  key.rounds()
         }
     }
 }
\ No newline at end of file
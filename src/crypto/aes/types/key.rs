@@ -0,0 +1,234 @@
+use crate::types::{Byte, SecretBytes};
+use crate::crypto::aes::traits::KeyExpansion;
+
+use crate::crypto::aes::consts::SBOX;
+use crate::math::AesField;
+
+use rand::Rng;
+
+use std::cmp;
+use std::fmt;
+
+pub struct Key
+{
+    key:        SecretBytes,
+    block_size: usize,
+    rounds:     usize
+}
+
+impl Key
+{
+    pub fn new(key: &[Byte]) -> Key
+    {
+        Key::from_secret(SecretBytes::new(key))
+    }
+
+    pub fn from_secret(key: SecretBytes) -> Key
+    {
+        match key.len()
+        {
+            16 | 24 | 32 => Key::rijndael(key, 4),
+            _ => panic!("Malformed key")
+        }
+    }
+
+    // Original Rijndael, key and block (in words) from 128 to 256 bits by steps of 32
+    pub fn rijndael(key: SecretBytes, block_size: usize) -> Key
+    {
+        if !(4..=8).contains(&block_size) { panic!("Malformed block size"); }
+
+        if !key.len().is_multiple_of(4) || !(4..=8).contains(&(key.len() / 4)) { panic!("Malformed key"); }
+
+        Key {
+            rounds:     6 + cmp::max(key.len() / 4, block_size),
+            key:        key,
+            block_size: block_size
+        }
+    }
+
+    // size in bytes, 16, 24 or 32
+    pub fn random<R: Rng + ?Sized>(size: usize, rng: &mut R) -> Key
+    {
+        let mut key = SecretBytes::zeroed(size);
+        rng.fill_bytes(key.expose_mut());
+
+        Key::from_secret(key)
+    }
+
+    pub fn secret(&self) -> &SecretBytes
+    {
+        &self.key
+    }
+
+    // Words
+    pub fn block_size(&self) -> usize
+    {
+        self.block_size
+    }
+
+    pub fn rounds(&self) -> usize
+    {
+        self.rounds
+    }
+
+    pub fn length(&self) -> usize
+    {
+        // Bytes (already 8 bits)
+        return self.key.len() / 4;
+    }
+
+    fn sub_word(&self, w: [Byte; 4]) -> [Byte; 4]
+    {
+        w.map( |b| SBOX[b as usize] )
+    }
+
+    fn rot_word(&self, w: [Byte; 4]) -> [Byte; 4]
+    {
+        return [w[1], w[2], w[3], w[0]];
+    }
+}
+
+// x^(i - 1) in GF(2^8), the table only goes as far as AES needs
+fn rcon(i: usize) -> Byte
+{
+    AesField::new(0x02).pow(i as u32 - 1).value()
+}
+
+impl KeyExpansion for Key
+{
+    fn expand(&self) -> SecretBytes
+    {
+        let mut rounds_key = SecretBytes::zeroed(4 * self.block_size * (self.rounds + 1));
+        let words = rounds_key.expose_mut();
+
+        words[..self.key.len()].copy_from_slice(self.key.expose());
+
+        for i in self.length()..self.block_size * (self.rounds + 1)
+        {
+            let mut t = [words[4*i - 4], words[4*i - 3], words[4*i - 2], words[4*i - 1]];
+
+            if i % self.length() == 0
+            {
+                t = self.sub_word(self.rot_word(t));
+                t[0] ^= rcon(i / self.length());
+            }
+            else if self.length() > 6 && i % self.length() == 4
+            {
+                t = self.sub_word(t);
+            }
+
+            for j in 0..4
+            {
+                words[4*i + j] = t[j] ^ words[4*(i - self.length()) + j];
+            }
+        }
+
+        return rounds_key;
+    }
+}
+
+impl From<&str> for Key
+{
+    fn from(s: &str) -> Self
+    {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl fmt::Display for Key
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        match self.block_size
+        {
+            4 => write!(f, "AES-{} key {}", 8 * self.key.len(), self.key),
+            _ => write!(f, "Rijndael-{}/{} key {}", 32 * self.block_size, 8 * self.key.len(), self.key)
+        }
+    }
+}
+
+impl fmt::Debug for Key
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "Key({:?})", self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Key;
+    use crate::types::SecretBytes;
+    use crate::crypto::aes::traits::KeyExpansion;
+
+    #[test]
+    fn expand()
+    {
+        let key = Key::new(&[0x00; 16]);
+
+        assert_eq!(
+            vec![
+                0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,
+                0x62,0x63,0x63,0x63,0x62,0x63,0x63,0x63,0x62,0x63,0x63,0x63,0x62,0x63,0x63,0x63,
+                0x9b,0x98,0x98,0xc9,0xf9,0xfb,0xfb,0xaa,0x9b,0x98,0x98,0xc9,0xf9,0xfb,0xfb,0xaa,
+                0x90,0x97,0x34,0x50,0x69,0x6c,0xcf,0xfa,0xf2,0xf4,0x57,0x33,0x0b,0x0f,0xac,0x99,
+                0xee,0x06,0xda,0x7b,0x87,0x6a,0x15,0x81,0x75,0x9e,0x42,0xb2,0x7e,0x91,0xee,0x2b,
+                0x7f,0x2e,0x2b,0x88,0xf8,0x44,0x3e,0x09,0x8d,0xda,0x7c,0xbb,0xf3,0x4b,0x92,0x90,
+                0xec,0x61,0x4b,0x85,0x14,0x25,0x75,0x8c,0x99,0xff,0x09,0x37,0x6a,0xb4,0x9b,0xa7,
+                0x21,0x75,0x17,0x87,0x35,0x50,0x62,0x0b,0xac,0xaf,0x6b,0x3c,0xc6,0x1b,0xf0,0x9b,
+                0x0e,0xf9,0x03,0x33,0x3b,0xa9,0x61,0x38,0x97,0x06,0x0a,0x04,0x51,0x1d,0xfa,0x9f,
+                0xb1,0xd4,0xd8,0xe2,0x8a,0x7d,0xb9,0xda,0x1d,0x7b,0xb3,0xde,0x4c,0x66,0x49,0x41,
+                0xb4,0xef,0x5b,0xcb,0x3e,0x92,0xe2,0x11,0x23,0xe9,0x51,0xcf,0x6f,0x8f,0x18,0x8e
+            ],
+            key.expand().expose()
+        );
+
+        let key = Key::new(&[0xff; 16]);
+
+        assert_eq!(
+            vec![
+                0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff,
+                0xe8,0xe9,0xe9,0xe9,0x17,0x16,0x16,0x16,0xe8,0xe9,0xe9,0xe9,0x17,0x16,0x16,0x16,
+                0xad,0xae,0xae,0x19,0xba,0xb8,0xb8,0x0f,0x52,0x51,0x51,0xe6,0x45,0x47,0x47,0xf0,
+                0x09,0x0e,0x22,0x77,0xb3,0xb6,0x9a,0x78,0xe1,0xe7,0xcb,0x9e,0xa4,0xa0,0x8c,0x6e,
+                0xe1,0x6a,0xbd,0x3e,0x52,0xdc,0x27,0x46,0xb3,0x3b,0xec,0xd8,0x17,0x9b,0x60,0xb6,
+                0xe5,0xba,0xf3,0xce,0xb7,0x66,0xd4,0x88,0x04,0x5d,0x38,0x50,0x13,0xc6,0x58,0xe6,
+                0x71,0xd0,0x7d,0xb3,0xc6,0xb6,0xa9,0x3b,0xc2,0xeb,0x91,0x6b,0xd1,0x2d,0xc9,0x8d,
+                0xe9,0x0d,0x20,0x8d,0x2f,0xbb,0x89,0xb6,0xed,0x50,0x18,0xdd,0x3c,0x7d,0xd1,0x50,
+                0x96,0x33,0x73,0x66,0xb9,0x88,0xfa,0xd0,0x54,0xd8,0xe2,0x0d,0x68,0xa5,0x33,0x5d,
+                0x8b,0xf0,0x3f,0x23,0x32,0x78,0xc5,0xf3,0x66,0xa0,0x27,0xfe,0x0e,0x05,0x14,0xa3,
+                0xd6,0x0a,0x35,0x88,0xe4,0x72,0xf0,0x7b,0x82,0xd2,0xd7,0x85,0x8c,0xd7,0xc3,0x26
+            ],
+            key.expand().expose()
+        )
+    }
+
+    #[test]
+    fn format()
+    {
+        let key = Key::from("YELLOW SUBMARINE");
+
+        assert_eq!("AES-128 key [REDACTED]", key.to_string());
+        assert_eq!("Key(SecretBytes([REDACTED; 16]))", format!("{:?}", key));
+        assert_eq!("59454c4c4f57205355424d4152494e45", key.secret().reveal().to_string());
+        assert_eq!("Rijndael-160/224 key [REDACTED]", Key::rijndael(SecretBytes::new(&[0; 28]), 5).to_string());
+    }
+
+    #[test]
+    fn rijndael()
+    {
+        let key = Key::rijndael(SecretBytes::new(&[0; 16]), 8);
+
+        assert_eq!(8, key.block_size());
+        assert_eq!(14, key.rounds());
+        assert_eq!(4 * 8 * 15, key.expand().len());
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed key")]
+    fn rijndael_malformed()
+    {
+        Key::rijndael(SecretBytes::new(&[0; 18]), 4);
+    }
+}
\ No newline at end of file
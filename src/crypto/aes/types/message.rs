@@ -0,0 +1,82 @@
+use rand::Rng;
+
+use crate::types::Bytes;
+use crate::traits::Random;
+use crate::crypto::aes::{Context, Key};
+use crate::crypto::modes::CounterLayout;
+
+pub struct Message
+{
+    pub key:     Key,
+    pub content: Bytes,
+    pub iv:      Option<Bytes>,
+    pub nonce:   Option<Bytes>,
+
+    pub counter_layout: CounterLayout
+}
+
+impl Message
+{
+    pub fn from(content: Bytes, key: Key) -> Self
+    {
+        Message {
+            content: content,
+            key:     key,
+            iv:      None,
+            nonce:   None,
+
+            counter_layout: CounterLayout::CRYPTOPALS
+        }
+    }
+
+    pub fn with_iv(mut self, iv: Bytes) -> Self
+    {
+        if iv.len() != 4 * Context::from_key(&self.key).block_size
+        {
+            panic!("Malformed IV");
+        }
+
+        self.iv = Some(iv);
+        self
+    }
+
+    pub fn with_random_iv<R: Rng + ?Sized>(self, rng: &mut R) -> Self
+    {
+        let size = 4 * Context::from_key(&self.key).block_size;
+
+        self.with_iv(Bytes::random(size, rng))
+    }
+
+    pub fn iv(&self) -> Bytes
+    {
+        if self.iv.is_none()
+        {
+            panic!("No IV specified");
+        }
+
+        self.iv.clone().unwrap()
+    }
+
+    // CTR nonce, its size is the block size minus the counter size of the layout
+    pub fn with_nonce(mut self, nonce: Bytes) -> Self
+    {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    pub fn with_counter_layout(mut self, layout: CounterLayout) -> Self
+    {
+        self.counter_layout = layout;
+        self
+    }
+
+    pub fn nonce(&self) -> Bytes
+    {
+        if self.nonce.is_none()
+        {
+            panic!("No nonce specified");
+        }
+
+        self.nonce.clone().unwrap()
+    }
+}
@@ -1,9 +1,13 @@
+mod aes;
 mod state;
 mod key;
 mod context;
 mod message;
+mod rijndael;
 
+pub use aes::Aes;
 pub use state::State;
 pub use key::Key;
 pub use context::Context;
-pub use message::Message;
\ No newline at end of file
+pub use message::Message;
+pub use rijndael::Rijndael;
\ No newline at end of file
@@ -0,0 +1,85 @@
+use crate::types::{Byte, SecretBytes};
+use crate::crypto::BlockCipher;
+use crate::crypto::aes::{cipher, Context, Key};
+use crate::crypto::aes::traits::KeyExpansion;
+
+// Any Rijndael block and key size on the reference State rounds, with the key schedule cached
+// (Aes is the fast path for 128-bit blocks)
+pub struct Rijndael
+{
+    ctx:        Context,
+    round_keys: SecretBytes
+}
+
+impl Rijndael
+{
+    pub fn new(key: &Key) -> Rijndael
+    {
+        Rijndael {
+            ctx:        Context::from_key(key),
+            round_keys: key.expand()
+        }
+    }
+}
+
+impl BlockCipher for Rijndael
+{
+    fn block_size(&self) -> usize
+    {
+        4 * self.ctx.block_size
+    }
+
+    fn encrypt_block(&self, block: &mut [Byte])
+    {
+        let output = cipher::encrypt_expanded(&self.ctx, block, self.round_keys.expose());
+
+        block.copy_from_slice(&output);
+    }
+
+    fn decrypt_block(&self, block: &mut [Byte])
+    {
+        let output = cipher::decrypt_expanded(&self.ctx, block, self.round_keys.expose());
+
+        block.copy_from_slice(&output);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Rijndael;
+    use crate::crypto::BlockCipher;
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::{Bytes, SecretBytes};
+    use crate::traits::FromHex;
+
+    #[test]
+    fn block_cipher()
+    {
+        let key = Key::rijndael(SecretBytes::new(&[0x2b; 24]), 6);
+        let rijndael = Rijndael::new(&key);
+        let mut block = [0x11; 24];
+
+        assert_eq!(24, rijndael.block_size());
+
+        rijndael.encrypt_block(&mut block);
+        assert_ne!([0x11; 24], block);
+
+        rijndael.decrypt_block(&mut block);
+        assert_eq!([0x11; 24], block);
+    }
+
+    #[test]
+    fn matches_aes()
+    {
+        let key  = Key::new(&Bytes::from_hex("000102030405060708090a0b0c0d0e0f"));
+        let mut a = Bytes::from_hex("00112233445566778899aabbccddeeff");
+        let mut b = a.clone();
+
+        BlockCipher::encrypt_block(&Rijndael::new(&key), &mut a);
+        BlockCipher::encrypt_block(&Aes::new(&key), &mut b);
+
+        assert_eq!(Bytes::from_hex("69c4e0d86a7b0430d8cdb78070b4c55a"), a);
+        assert_eq!(a, b);
+    }
+}
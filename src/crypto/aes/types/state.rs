@@ -1,10 +1,15 @@
-use crate::lib::types::{Byte, ByteVec, Matrix};
-use crate::lib::crypto::aes::traits::Ops;
-use crate::lib::crypto::aes::consts::{SBOX, SBOX_INV};
-use crate::lib::math::gf2_8::mul;
+use crate::types::{Byte, Bytes, Matrix};
+use crate::crypto::aes::traits::Ops;
+use crate::crypto::aes::consts::{SBOX, SBOX_INV};
+use crate::math::AesField;
 
 use std::fmt;
 
+fn mul(a: Byte, b: Byte) -> Byte
+{
+    (AesField::new(a) * AesField::new(b)).value()
+}
+
 pub struct State
 {
     bytes: Matrix<Byte>
@@ -38,9 +43,9 @@ impl State
         state
     }
 
-    pub fn output(&self) -> ByteVec
+    pub fn output(&self) -> Bytes
     {
-        let mut output = vec![0x0; 16];
+        let mut output = vec![0x0; Self::ROW_COUNT * self.block_size()];
 
         for r in 0..Self::ROW_COUNT
         {
@@ -50,14 +55,14 @@ impl State
             }
         }
 
-        return output;
+        return Bytes::from(output);
     }
 
     fn assert_size(&self, block: &[Byte])
     {
-        if block.len() != Self::ROW_COUNT * self.bytes.height()
+        if block.len() != Self::ROW_COUNT * self.block_size()
         {
-            panic!("Malformed byte-array {} != {}", block.len(), Self::ROW_COUNT * self.bytes.height());
+            panic!("Malformed byte-array {} != {}", block.len(), Self::ROW_COUNT * self.block_size());
         }
     }
 
@@ -66,18 +71,33 @@ impl State
         self.bytes.width()
     }
 
+    // Rijndael shifts rows further apart for the wider blocks
+    fn shift_offsets(&self) -> [usize; 4]
+    {
+        match self.block_size()
+        {
+            7 => [0, 1, 2, 4],
+            8 => [0, 1, 3, 4],
+            _ => [0, 1, 2, 3]
+        }
+    }
+
     fn shift_row(&mut self, row: usize, shift: usize)
     {
-        self.bytes[row] = (0..Self::ROW_COUNT)
+        let shifted: Vec<Byte> = (0..self.block_size())
             .map( |i| self.bytes[row][(shift + i) % self.block_size()])
             .collect();
+
+        self.bytes[row].copy_from_slice(&shifted);
     }
 
     fn inv_shift_row(&mut self, row: usize, shift: usize)
     {
-        self.bytes[row] = (0..Self::ROW_COUNT)
+        let shifted: Vec<Byte> = (0..self.block_size())
             .map( |i| self.bytes[row][(self.block_size() - shift + i) % self.block_size()])
             .collect();
+
+        self.bytes[row].copy_from_slice(&shifted);
     }
 }
 
@@ -109,9 +129,11 @@ impl Ops for State
 
     fn shift_rows(&mut self)
     {
+        let offsets = self.shift_offsets();
+
         for i in 1..Self::ROW_COUNT
         {
-            self.shift_row(i, i);
+            self.shift_row(i, offsets[i]);
         }
     }
 
@@ -141,9 +163,11 @@ impl Ops for State
 
     fn inv_shift_rows(&mut self)
     {
+        let offsets = self.shift_offsets();
+
         for i in 1..Self::ROW_COUNT
         {
-            self.inv_shift_row(i, i);
+            self.inv_shift_row(i, offsets[i]);
         }
     }
 
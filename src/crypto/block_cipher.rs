@@ -0,0 +1,107 @@
+use crate::types::{Byte, SecretBytes};
+
+// Keyed permutation on fixed-size blocks, what every mode in crypto::modes is built on
+pub trait BlockCipher
+{
+    // Bytes
+    fn block_size(&self) -> usize;
+
+    // Panics if block is not block_size() bytes long
+    fn encrypt_block(&self, block: &mut [Byte]);
+    fn decrypt_block(&self, block: &mut [Byte]);
+
+    // Consecutive blocks, data must be a multiple of the block size
+    fn encrypt_blocks(&self, data: &mut [Byte])
+    {
+        for block in blocks_mut(data, self.block_size())
+        {
+            self.encrypt_block(block);
+        }
+    }
+
+    fn decrypt_blocks(&self, data: &mut [Byte])
+    {
+        for block in blocks_mut(data, self.block_size())
+        {
+            self.decrypt_block(block);
+        }
+    }
+}
+
+impl<C: BlockCipher + ?Sized> BlockCipher for &C
+{
+    fn block_size(&self) -> usize
+    {
+        (**self).block_size()
+    }
+
+    fn encrypt_block(&self, block: &mut [Byte])
+    {
+        (**self).encrypt_block(block)
+    }
+
+    fn decrypt_block(&self, block: &mut [Byte])
+    {
+        (**self).decrypt_block(block)
+    }
+
+    fn encrypt_blocks(&self, data: &mut [Byte])
+    {
+        (**self).encrypt_blocks(data)
+    }
+
+    fn decrypt_blocks(&self, data: &mut [Byte])
+    {
+        (**self).decrypt_blocks(data)
+    }
+}
+
+impl<C: BlockCipher + ?Sized> BlockCipher for Box<C>
+{
+    fn block_size(&self) -> usize
+    {
+        (**self).block_size()
+    }
+
+    fn encrypt_block(&self, block: &mut [Byte])
+    {
+        (**self).encrypt_block(block)
+    }
+
+    fn decrypt_block(&self, block: &mut [Byte])
+    {
+        (**self).decrypt_block(block)
+    }
+
+    fn encrypt_blocks(&self, data: &mut [Byte])
+    {
+        (**self).encrypt_blocks(data)
+    }
+
+    fn decrypt_blocks(&self, data: &mut [Byte])
+    {
+        (**self).decrypt_blocks(data)
+    }
+}
+
+// Block ciphers able to spawn an instance of the same kind under another key,
+// for modes deriving per-message keys (GCM-SIV)
+pub trait Rekey: BlockCipher + Sized
+{
+    // Bytes
+    fn key_size(&self) -> usize;
+
+    // Panics if the key size is not supported
+    fn rekey(&self, key: &SecretBytes) -> Self;
+}
+
+// Panics if data is not a multiple of block_size
+pub(crate) fn blocks_mut(data: &mut [Byte], block_size: usize) -> impl Iterator<Item = &mut [Byte]>
+{
+    if !data.len().is_multiple_of(block_size)
+    {
+        panic!("Malformed data ({} is not a multiple of {})", data.len(), block_size);
+    }
+
+    data.chunks_exact_mut(block_size)
+}
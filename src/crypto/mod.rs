@@ -0,0 +1,7 @@
+pub mod xor;
+pub mod aes;
+pub mod modes;
+
+mod block_cipher;
+
+pub use block_cipher::{BlockCipher, Rekey};
@@ -0,0 +1,93 @@
+use crate::types::{Byte, Bytes};
+use crate::crypto::BlockCipher;
+use crate::crypto::block_cipher::blocks_mut;
+
+use super::{Mode, pad, unpad};
+
+pub struct Cbc<C: BlockCipher>
+{
+    cipher: C,
+    iv:     Bytes
+}
+
+impl<C: BlockCipher> Cbc<C>
+{
+    pub fn new(cipher: C, iv: &[Byte]) -> Self
+    {
+        if iv.len() != cipher.block_size()
+        {
+            panic!("Malformed IV ({} != {})", iv.len(), cipher.block_size());
+        }
+
+        Cbc { cipher: cipher, iv: Bytes::from(iv) }
+    }
+}
+
+impl<C: BlockCipher> Mode for Cbc<C>
+{
+    fn encrypt(&self, plaintext: &[Byte]) -> Bytes
+    {
+        let mut data = pad(plaintext, self.cipher.block_size());
+        let mut previous = self.iv.clone();
+
+        for block in blocks_mut(&mut data, self.cipher.block_size())
+        {
+            for i in 0..block.len() { block[i] ^= previous[i]; }
+
+            self.cipher.encrypt_block(block);
+
+            previous.copy_from_slice(block);
+        }
+
+        data
+    }
+
+    fn decrypt(&self, ciphertext: &[Byte]) -> Bytes
+    {
+        let mut data = Bytes::from(ciphertext);
+        let mut previous = self.iv.clone();
+
+        for block in blocks_mut(&mut data, self.cipher.block_size())
+        {
+            let current = Bytes::from(&block[..]);
+
+            self.cipher.decrypt_block(block);
+
+            for i in 0..block.len() { block[i] ^= previous[i]; }
+
+            previous = current;
+        }
+
+        unpad(&data, self.cipher.block_size())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Cbc;
+    use crate::crypto::modes::Mode;
+    use crate::crypto::modes::tests::Toy;
+    use crate::types::Bytes;
+    use crate::attacks;
+
+    #[test]
+    fn toy()
+    {
+        let cbc = Cbc::new(Toy([0x2a; 8]), &[0x01; 8]);
+        let plaintext = Bytes::from("YELLOW SUBMARINEYELLOW SUBMARINE");
+
+        let ciphertext = cbc.encrypt(&plaintext);
+
+        assert_eq!(40, ciphertext.len());
+        assert_eq!(plaintext, cbc.decrypt(&ciphertext));
+        assert_eq!(0.0, attacks::aes::detect_ecb(&ciphertext, 8));
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed IV")]
+    fn malformed_iv()
+    {
+        Cbc::new(Toy([0x2a; 8]), &[0x01; 16]);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::types::{Byte, Bytes};
+use crate::crypto::BlockCipher;
+
+use super::Mode;
+
+// Bits of ciphertext fed back into the shift register per cipher call
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Segment
+{
+    // CFB1
+    Bit,
+    // CFB8
+    Byte,
+    // CFB128 for AES
+    Block
+}
+
+// Stream mode, no padding
+pub struct Cfb<C: BlockCipher>
+{
+    cipher:  C,
+    iv:      Bytes,
+    segment: Segment
+}
+
+impl<C: BlockCipher> Cfb<C>
+{
+    pub fn new(cipher: C, iv: &[Byte], segment: Segment) -> Self
+    {
+        if iv.len() != cipher.block_size()
+        {
+            panic!("Malformed IV ({} != {})", iv.len(), cipher.block_size());
+        }
+
+        Cfb { cipher: cipher, iv: Bytes::from(iv), segment: segment }
+    }
+
+    pub fn encryptor(&self) -> CfbStream<'_, C>
+    {
+        CfbStream::new(self, false)
+    }
+
+    pub fn decryptor(&self) -> CfbStream<'_, C>
+    {
+        CfbStream::new(self, true)
+    }
+}
+
+impl<C: BlockCipher> Mode for Cfb<C>
+{
+    fn encrypt(&self, plaintext: &[Byte]) -> Bytes
+    {
+        let mut data = Bytes::from(plaintext);
+
+        self.encryptor().update(&mut data);
+
+        data
+    }
+
+    fn decrypt(&self, ciphertext: &[Byte]) -> Bytes
+    {
+        let mut data = Bytes::from(ciphertext);
+
+        self.decryptor().update(&mut data);
+
+        data
+    }
+}
+
+// Incremental CFB, data can be given in chunks of any size
+pub struct CfbStream<'a, C: BlockCipher>
+{
+    cipher:    &'a C,
+    segment:   Segment,
+    decrypt:   bool,
+    register:  Bytes,
+    keystream: Bytes,
+    feedback:  Bytes,
+    position:  usize
+}
+
+impl<'a, C: BlockCipher> CfbStream<'a, C>
+{
+    fn new(cfb: &'a Cfb<C>, decrypt: bool) -> Self
+    {
+        CfbStream {
+            cipher:    &cfb.cipher,
+            segment:   cfb.segment,
+            decrypt:   decrypt,
+            register:  cfb.iv.clone(),
+            keystream: cfb.iv.clone(),
+            feedback:  cfb.iv.clone(),
+            position:  0
+        }
+    }
+
+    // Encrypts or decrypts data in place
+    pub fn update(&mut self, data: &mut [Byte])
+    {
+        for byte in data.iter_mut()
+        {
+            *byte = match self.segment
+            {
+                Segment::Bit => self.update_bits(*byte),
+                _            => self.update_byte(*byte)
+            };
+        }
+    }
+
+    fn segment_size(&self) -> usize
+    {
+        match self.segment
+        {
+            Segment::Block => self.register.len(),
+            _              => 1
+        }
+    }
+
+    fn update_byte(&mut self, input: Byte) -> Byte
+    {
+        let size = self.segment_size();
+
+        if self.position == 0
+        {
+            self.keystream.copy_from_slice(&self.register);
+            self.cipher.encrypt_block(&mut self.keystream);
+        }
+
+        let output = input ^ self.keystream[self.position];
+
+        self.feedback[self.position] = if self.decrypt { input } else { output };
+        self.position += 1;
+
+        if self.position == size
+        {
+            // Shift the register left by one segment, the ciphertext coming in on the right
+            self.register.rotate_left(size);
+
+            let length = self.register.len();
+            self.register[length - size..].copy_from_slice(&self.feedback[..size]);
+
+            self.position = 0;
+        }
+
+        output
+    }
+
+    // One cipher call per bit, most significant bit first
+    fn update_bits(&mut self, input: Byte) -> Byte
+    {
+        let mut output = 0;
+
+        for i in (0..8).rev()
+        {
+            self.keystream.copy_from_slice(&self.register);
+            self.cipher.encrypt_block(&mut self.keystream);
+
+            let bit = ((input >> i) & 1) ^ (self.keystream[0] >> 7);
+            let feedback = if self.decrypt { (input >> i) & 1 } else { bit };
+
+            shift_left(&mut self.register, feedback);
+            output |= bit << i;
+        }
+
+        output
+    }
+}
+
+// Shifts the whole register left by one bit
+fn shift_left(register: &mut [Byte], bit: Byte)
+{
+    let mut carry = bit;
+
+    for byte in register.iter_mut().rev()
+    {
+        let next = *byte >> 7;
+
+        *byte = (*byte << 1) | carry;
+        carry = next;
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{Cfb, Segment};
+    use crate::crypto::modes::Mode;
+    use crate::crypto::modes::tests::Toy;
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::Bytes;
+    use crate::traits::FromHex;
+
+    fn aes() -> Aes
+    {
+        Aes::new(&Key::new(&Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c")))
+    }
+
+    fn iv() -> Bytes
+    {
+        Bytes::from_hex("000102030405060708090a0b0c0d0e0f")
+    }
+
+    #[test]
+    fn cfb1()
+    {
+        // SP 800-38A F.3.1 and F.3.2, 16 segments of 1 bit
+        let cfb = Cfb::new(aes(), &iv(), Segment::Bit);
+
+        assert_eq!(Bytes::from_hex("68b3"), cfb.encrypt(&Bytes::from_hex("6bc1")));
+        assert_eq!(Bytes::from_hex("6bc1"), cfb.decrypt(&Bytes::from_hex("68b3")));
+    }
+
+    #[test]
+    fn cfb8()
+    {
+        // SP 800-38A F.3.7 and F.3.8
+        let cfb = Cfb::new(aes(), &iv(), Segment::Byte);
+
+        let plaintext  = Bytes::from_hex("6bc1bee22e409f96e93d7e117393172aae2d");
+        let ciphertext = Bytes::from_hex("3b79424c9c0dd436bace9e0ed4586a4f32b9");
+
+        assert_eq!(ciphertext, cfb.encrypt(&plaintext));
+        assert_eq!(plaintext, cfb.decrypt(&ciphertext));
+    }
+
+    #[test]
+    fn cfb128()
+    {
+        // SP 800-38A F.3.13 and F.3.14
+        let cfb = Cfb::new(aes(), &iv(), Segment::Block);
+
+        let plaintext = Bytes::from_hex(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+        );
+        let ciphertext = Bytes::from_hex(
+            "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b\
+             26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6"
+        );
+
+        assert_eq!(ciphertext, cfb.encrypt(&plaintext));
+        assert_eq!(plaintext, cfb.decrypt(&ciphertext));
+    }
+
+    #[test]
+    fn stream()
+    {
+        let plaintext = Bytes::from("CFB as a stream, in chunks that do not line up with blocks");
+
+        for segment in [Segment::Bit, Segment::Byte, Segment::Block]
+        {
+            let cfb = Cfb::new(Toy([0x2a; 8]), &[0x01; 8], segment);
+            let expected = cfb.encrypt(&plaintext);
+
+            let mut data = plaintext.clone();
+            let mut encryptor = cfb.encryptor();
+
+            for chunk in data.chunks_mut(5) { encryptor.update(chunk); }
+            assert_eq!(expected, data);
+
+            let mut decryptor = cfb.decryptor();
+
+            for chunk in data.chunks_mut(11) { decryptor.update(chunk); }
+            assert_eq!(plaintext, data);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed IV")]
+    fn malformed_iv()
+    {
+        Cfb::new(Toy([0x2a; 8]), &[0x01; 16], Segment::Byte);
+    }
+}
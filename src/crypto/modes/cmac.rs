@@ -0,0 +1,124 @@
+use std::convert::TryInto;
+
+use crate::types::{Byte, Bytes, wipe};
+use crate::crypto::BlockCipher;
+
+// CMAC (RFC 4493, SP 800-38B) over a 128-bit block cipher
+pub struct Cmac<C: BlockCipher>
+{
+    cipher: C,
+    k1:     [Byte; 16],
+    k2:     [Byte; 16]
+}
+
+impl<C: BlockCipher> Cmac<C>
+{
+    pub fn new(cipher: C) -> Self
+    {
+        if cipher.block_size() != 16
+        {
+            panic!("Malformed block cipher ({} bytes blocks, CMAC needs 16)", cipher.block_size());
+        }
+
+        let mut l = [0; 16];
+        cipher.encrypt_block(&mut l);
+
+        let k1 = dbl(&l);
+        let k2 = dbl(&k1);
+
+        wipe(&mut l);
+
+        Cmac { cipher: cipher, k1: k1, k2: k2 }
+    }
+
+    pub fn cipher(&self) -> &C
+    {
+        &self.cipher
+    }
+
+    pub fn mac(&self, message: &[Byte]) -> [Byte; 16]
+    {
+        let blocks = message.len().div_ceil(16).max(1);
+        let mut state = [0; 16];
+
+        for i in 0..blocks - 1
+        {
+            for j in 0..16 { state[j] ^= message[16*i + j]; }
+
+            self.cipher.encrypt_block(&mut state);
+        }
+
+        // Complete last block is xored with K1, a partial one padded with 10* and xored with K2
+        let last = &message[16 * (blocks - 1)..];
+        let key  = if last.len() == 16 { &self.k1 } else { &self.k2 };
+
+        for j in 0..last.len() { state[j] ^= last[j]; }
+        if last.len() < 16 { state[last.len()] ^= 0x80; }
+        for j in 0..16 { state[j] ^= key[j]; }
+
+        self.cipher.encrypt_block(&mut state);
+
+        state
+    }
+
+    pub fn mac_bytes(&self, message: &[Byte]) -> Bytes
+    {
+        Bytes::from(&self.mac(message)[..])
+    }
+}
+
+impl<C: BlockCipher> Drop for Cmac<C>
+{
+    fn drop(&mut self)
+    {
+        wipe(&mut self.k1);
+        wipe(&mut self.k2);
+    }
+}
+
+// Multiplication by x in GF(2^128), big-endian blocks (CMAC and S2V "dbl")
+pub(crate) fn dbl(block: &[Byte]) -> [Byte; 16]
+{
+    let block: &[Byte; 16] = block.try_into().expect("Malformed block");
+    let value = u128::from_be_bytes(*block);
+
+    // No branch on the top bit, L and the S2V accumulator are secret
+    let carry = 0x87 & 0u128.wrapping_sub(value >> 127);
+
+    ((value << 1) ^ carry).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{Cmac, dbl};
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::Bytes;
+    use crate::traits::FromHex;
+
+    #[test]
+    fn rfc4493()
+    {
+        let cmac = Cmac::new(Aes::new(&Key::new(&Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c"))));
+
+        let message = Bytes::from_hex(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411"
+        );
+
+        assert_eq!(Bytes::from_hex("bb1d6929e95937287fa37d129b756746"), cmac.mac_bytes(&[]));
+        assert_eq!(Bytes::from_hex("070a16b46b4d4144f79bdd9dd04a287c"), cmac.mac_bytes(&message[..16]));
+        assert_eq!(Bytes::from_hex("dfa66747de9ae63030ca32611497c827"), cmac.mac_bytes(&message));
+    }
+
+    #[test]
+    fn subkeys()
+    {
+        // RFC 4493 2.4, L = 7df76b0c1ab899b33e42f047b91b546f
+        let l = Bytes::from_hex("7df76b0c1ab899b33e42f047b91b546f");
+        let k1 = dbl(&l);
+
+        assert_eq!(Bytes::from_hex("fbeed618357133667c85e08f7236a8de"), Bytes::from(&k1[..]));
+        assert_eq!(Bytes::from_hex("f7ddac306ae266ccf90bc11ee46d513b"), Bytes::from(&dbl(&k1)[..]));
+    }
+}
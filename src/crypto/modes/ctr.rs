@@ -0,0 +1,262 @@
+use crate::types::{Byte, Bytes};
+use crate::crypto::BlockCipher;
+
+use super::Mode;
+
+// Blocks of keystream generated per call to the cipher, so batched backends can pipeline
+const BATCH: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness
+{
+    Little,
+    Big
+}
+
+// Counter block = nonce || counter, the counter taking the last counter_size bytes
+// and wrapping around modulo 2^(8 * counter_size)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CounterLayout
+{
+    pub counter_size: usize,
+    pub endianness:   Endianness
+}
+
+impl CounterLayout
+{
+    // 64-bit nonce, 64-bit little-endian counter
+    pub const CRYPTOPALS: CounterLayout = CounterLayout { counter_size: 8, endianness: Endianness::Little };
+
+    // 96-bit nonce, 32-bit big-endian counter (SP 800-38A, GCM)
+    pub const NIST: CounterLayout = CounterLayout { counter_size: 4, endianness: Endianness::Big };
+}
+
+// Stream mode, no padding and the keystream can be taken from any byte offset
+pub struct Ctr<C: BlockCipher>
+{
+    cipher:  C,
+    nonce:   Bytes,
+    layout:  CounterLayout,
+    initial: u128
+}
+
+impl<C: BlockCipher> Ctr<C>
+{
+    // Counter starts at 0
+    pub fn new(cipher: C, nonce: &[Byte], layout: CounterLayout) -> Self
+    {
+        if layout.counter_size == 0 || layout.counter_size > 16 || layout.counter_size > cipher.block_size()
+        {
+            panic!("Malformed counter layout ({} bytes counter)", layout.counter_size);
+        }
+
+        if nonce.len() + layout.counter_size != cipher.block_size()
+        {
+            panic!("Malformed nonce ({} != {})", nonce.len(), cipher.block_size() - layout.counter_size);
+        }
+
+        Ctr {
+            cipher:  cipher,
+            nonce:   Bytes::from(nonce),
+            layout:  layout,
+            initial: 0
+        }
+    }
+
+    pub fn with_counter(mut self, counter: u128) -> Self
+    {
+        self.initial = counter;
+        self
+    }
+
+    fn counter_block(&self, index: u128, block: &mut [Byte])
+    {
+        let size    = self.layout.counter_size;
+        let counter = match size
+        {
+            16 => self.initial.wrapping_add(index),
+            _  => self.initial.wrapping_add(index) & ((1 << (8 * size)) - 1)
+        };
+
+        let nonce_size = self.nonce.len();
+        block[..nonce_size].copy_from_slice(&self.nonce);
+
+        match self.layout.endianness
+        {
+            Endianness::Little => block[nonce_size..].copy_from_slice(&counter.to_le_bytes()[..size]),
+            Endianness::Big    => block[nonce_size..].copy_from_slice(&counter.to_be_bytes()[16 - size..])
+        }
+    }
+
+    // Keystream bytes starting at offset
+    pub fn keystream(&self, offset: u64, output: &mut [Byte])
+    {
+        output.iter_mut().for_each( |b| *b = 0 );
+
+        self.apply_keystream(offset, output);
+    }
+
+    // Xor data with the keystream starting at offset, this both encrypts and decrypts
+    pub fn apply_keystream(&self, offset: u64, data: &mut [Byte])
+    {
+        let block_size = self.cipher.block_size();
+        let mut index  = offset as u128 / block_size as u128;
+        let mut skip   = (offset % block_size as u64) as usize;
+        let mut position = 0;
+        let mut stream = vec![0; BATCH * block_size];
+
+        while position < data.len()
+        {
+            let blocks = ((skip + data.len() - position).div_ceil(block_size)).min(BATCH);
+            let stream = &mut stream[..blocks * block_size];
+
+            for (i, block) in stream.chunks_exact_mut(block_size).enumerate()
+            {
+                self.counter_block(index + i as u128, block);
+            }
+
+            self.cipher.encrypt_blocks(stream);
+
+            for b in stream[skip..].iter()
+            {
+                if position == data.len() { break; }
+
+                data[position] ^= b;
+                position += 1;
+            }
+
+            index += blocks as u128;
+            skip = 0;
+        }
+    }
+
+    // Ciphertext with the bytes at offset replaced by the encryption of plaintext
+    pub fn edit(&self, ciphertext: &[Byte], offset: usize, plaintext: &[Byte]) -> Bytes
+    {
+        let mut result = Bytes::from(ciphertext);
+        let end = offset + plaintext.len();
+
+        if end > result.len() { result.resize(end, 0); }
+
+        result[offset..end].copy_from_slice(plaintext);
+        self.apply_keystream(offset as u64, &mut result[offset..end]);
+
+        result
+    }
+}
+
+impl<C: BlockCipher> Mode for Ctr<C>
+{
+    fn encrypt(&self, plaintext: &[Byte]) -> Bytes
+    {
+        let mut data = Bytes::from(plaintext);
+
+        self.apply_keystream(0, &mut data);
+
+        data
+    }
+
+    fn decrypt(&self, ciphertext: &[Byte]) -> Bytes
+    {
+        self.encrypt(ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{Ctr, CounterLayout, Endianness};
+    use crate::crypto::modes::Mode;
+    use crate::crypto::modes::tests::Toy;
+    use crate::crypto::BlockCipher;
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::Bytes;
+    use crate::traits::{FromHex, FromBase64};
+
+    #[test]
+    fn cryptopals()
+    {
+        // Challenge 18
+        let ctr = Ctr::new(Aes::new(&Key::from("YELLOW SUBMARINE")), &[0; 8], CounterLayout::CRYPTOPALS);
+        let ciphertext = Bytes::from_base64("L77na/nrFsKvynd6HzOoG7GHTLXsTVu9qvY/2syLXzhPweyyMTJULu/6/kXX0KSvoOLSFQ==");
+
+        assert_eq!(
+            "Yo, VIP Let's kick it Ice, Ice, baby Ice, Ice, baby ",
+            ctr.decrypt(&ciphertext).to_utf8().unwrap()
+        );
+    }
+
+    #[test]
+    fn sp800_38a()
+    {
+        // F.5.1 CTR-AES128.Encrypt
+        let key = Key::new(&Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c"));
+        let ctr = Ctr::new(Aes::new(&key), &Bytes::from_hex("f0f1f2f3f4f5f6f7f8f9fafb"), CounterLayout::NIST)
+            .with_counter(0xfcfdfeff);
+
+        let plaintext = Bytes::from_hex(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+        );
+        let ciphertext = Bytes::from_hex(
+            "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+             5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee"
+        );
+
+        assert_eq!(ciphertext, ctr.encrypt(&plaintext));
+        assert_eq!(plaintext, ctr.decrypt(&ciphertext));
+    }
+
+    #[test]
+    fn seek()
+    {
+        let ctr = Ctr::new(Aes::new(&Key::from("YELLOW SUBMARINE")), &[0x42; 8], CounterLayout::CRYPTOPALS);
+        let mut stream = vec![0; 16 * 100];
+
+        ctr.keystream(0, &mut stream);
+
+        for offset in [0, 1, 15, 16, 17, 1000, 1583].iter()
+        {
+            let mut part = vec![0; 1600 - offset];
+            ctr.keystream(*offset as u64, &mut part);
+
+            assert_eq!(&stream[*offset..], &part[..]);
+        }
+    }
+
+    #[test]
+    fn edit()
+    {
+        let ctr = Ctr::new(Aes::new(&Key::from("YELLOW SUBMARINE")), &[0; 8], CounterLayout::CRYPTOPALS);
+        let ciphertext = ctr.encrypt(&Bytes::from("Lorem ipsum dolor sit amet"));
+
+        let edited = ctr.edit(&ciphertext, 6, b"IPSUM");
+        assert_eq!("Lorem IPSUM dolor sit amet", ctr.decrypt(&edited).to_utf8().unwrap());
+
+        let extended = ctr.edit(&ciphertext, 22, b"amet, consectetur");
+        assert_eq!("Lorem ipsum dolor sit amet, consectetur", ctr.decrypt(&extended).to_utf8().unwrap());
+    }
+
+    #[test]
+    fn counter_wraps()
+    {
+        let layout = CounterLayout { counter_size: 1, endianness: Endianness::Big };
+        let ctr = Ctr::new(Toy([0x2a; 8]), &[0x01; 7], layout).with_counter(0xff);
+        let mut stream = vec![0; 16];
+
+        ctr.keystream(0, &mut stream);
+
+        // Counter blocks ..01 ff then ..01 00
+        let mut expected = vec![0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0xff, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00];
+        Toy([0x2a; 8]).encrypt_blocks(&mut expected);
+
+        assert_eq!(expected, stream);
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed nonce")]
+    fn malformed_nonce()
+    {
+        Ctr::new(Aes::new(&Key::from("YELLOW SUBMARINE")), &[0; 12], CounterLayout::CRYPTOPALS);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::types::{Byte, Bytes};
+use crate::crypto::BlockCipher;
+
+use super::{Mode, pad, unpad};
+
+pub struct Ecb<C: BlockCipher>
+{
+    cipher: C
+}
+
+impl<C: BlockCipher> Ecb<C>
+{
+    pub fn new(cipher: C) -> Self
+    {
+        Ecb { cipher: cipher }
+    }
+}
+
+impl<C: BlockCipher> Mode for Ecb<C>
+{
+    fn encrypt(&self, plaintext: &[Byte]) -> Bytes
+    {
+        let mut data = pad(plaintext, self.cipher.block_size());
+
+        self.cipher.encrypt_blocks(&mut data);
+
+        data
+    }
+
+    fn decrypt(&self, ciphertext: &[Byte]) -> Bytes
+    {
+        let mut data = Bytes::from(ciphertext);
+
+        self.cipher.decrypt_blocks(&mut data);
+
+        unpad(&data, self.cipher.block_size())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Ecb;
+    use crate::crypto::modes::Mode;
+    use crate::crypto::modes::tests::Toy;
+    use crate::types::Bytes;
+    use crate::attacks;
+
+    #[test]
+    fn toy()
+    {
+        let ecb = Ecb::new(Toy([0x2a; 8]));
+        let plaintext = Bytes::from("YELLOW SUBMARINEYELLOW SUBMARINE");
+
+        let ciphertext = ecb.encrypt(&plaintext);
+
+        assert_eq!(40, ciphertext.len());
+        assert_eq!(plaintext, ecb.decrypt(&ciphertext));
+        assert!(attacks::aes::detect_ecb(&ciphertext, 8) > 0.0);
+    }
+}
@@ -0,0 +1,32 @@
+use std::error;
+use std::fmt;
+
+// Authenticated decryption failures, no plaintext is released with either
+#[derive(Debug, Clone, PartialEq)]
+pub enum AeadError
+{
+    InvalidTag,
+    TagSize { size: usize, expected: usize },
+    NonceSize { size: usize, expected: usize },
+    // Bytes, past what the mode's counter can cover
+    TooLong { length: usize, max: u64 },
+    // Associated data components, S2V takes at most 126
+    TooManyComponents { count: usize, max: usize }
+}
+
+impl fmt::Display for AeadError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        match self
+        {
+            Self::InvalidTag                       => write!(f, "authentication failed"),
+            Self::TagSize { size, expected }       => write!(f, "bad tag size ({} != {})", size, expected),
+            Self::NonceSize { size, expected }     => write!(f, "bad nonce size ({} != {})", size, expected),
+            Self::TooLong { length, max }          => write!(f, "input too long ({} > {} bytes)", length, max),
+            Self::TooManyComponents { count, max } => write!(f, "too many associated data components ({} > {})", count, max)
+        }
+    }
+}
+
+impl error::Error for AeadError {}
@@ -0,0 +1,330 @@
+use crate::types::{Byte, Bytes, ct_eq};
+use crate::crypto::BlockCipher;
+use crate::math::GF128;
+
+use super::{Ctr, CounterLayout, AeadError};
+
+// 2^32 - 2 blocks, past that the 32-bit counter wraps around to J0 and reuses the tag mask
+const MAX_LENGTH: u64 = ((1 << 32) - 2) * 16;
+
+// SP 800-38D 5.2.1.2, 128, 120, 112, 104, 96, then 64 and 32 bits for specific applications
+const TAG_SIZES: [usize; 7] = [16, 15, 14, 13, 12, 8, 4];
+
+// Galois/Counter Mode (SP 800-38D) over a 128-bit block cipher
+pub struct Gcm<C: BlockCipher>
+{
+    cipher:   C,
+    h:        GF128,
+    tag_size: usize
+}
+
+impl<C: BlockCipher> Gcm<C>
+{
+    // Full 128-bit tags
+    pub fn new(cipher: C) -> Self
+    {
+        if cipher.block_size() != 16
+        {
+            panic!("Malformed block cipher ({} bytes blocks, GCM needs 16)", cipher.block_size());
+        }
+
+        let mut h = [0; 16];
+        cipher.encrypt_block(&mut h);
+
+        Gcm { cipher: cipher, h: GF128::from_block(&h), tag_size: 16 }
+    }
+
+    // Truncated tags, 4, 8 or 12 to 16 bytes
+    pub fn with_tag_size(mut self, size: usize) -> Self
+    {
+        if !TAG_SIZES.contains(&size)
+        {
+            panic!("Malformed tag size ({} bytes)", size);
+        }
+
+        self.tag_size = size;
+        self
+    }
+
+    pub fn tag_size(&self) -> usize
+    {
+        self.tag_size
+    }
+
+    // H = E(0^128)
+    pub fn hash_key(&self) -> GF128
+    {
+        self.h
+    }
+
+    // Returns (ciphertext, tag), panics past 2^32 - 2 blocks of plaintext
+    pub fn seal(&self, iv: &[Byte], aad: &[Byte], plaintext: &[Byte]) -> (Bytes, Bytes)
+    {
+        if too_long(plaintext.len())
+        {
+            panic!("Malformed plaintext ({} > {} bytes)", plaintext.len(), MAX_LENGTH);
+        }
+
+        let j0 = self.pre_counter(iv);
+        let mut ciphertext = Bytes::from(plaintext);
+
+        self.ctr(&j0).apply_keystream(0, &mut ciphertext);
+
+        let tag = self.tag(&j0, aad, &ciphertext);
+
+        (ciphertext, tag)
+    }
+
+    // The tag is checked before anything is decrypted
+    pub fn open(&self, iv: &[Byte], aad: &[Byte], ciphertext: &[Byte], tag: &[Byte]) -> Result<Bytes, AeadError>
+    {
+        if tag.len() != self.tag_size
+        {
+            return Err(AeadError::TagSize { size: tag.len(), expected: self.tag_size });
+        }
+
+        if too_long(ciphertext.len())
+        {
+            return Err(AeadError::TooLong { length: ciphertext.len(), max: MAX_LENGTH });
+        }
+
+        // Any non-empty IV is allowed, 96 bits is the recommended size
+        if iv.is_empty()
+        {
+            return Err(AeadError::NonceSize { size: 0, expected: 12 });
+        }
+
+        let j0 = self.pre_counter(iv);
+
+        if !ct_eq(&self.tag(&j0, aad, ciphertext), tag)
+        {
+            return Err(AeadError::InvalidTag);
+        }
+
+        let mut plaintext = Bytes::from(ciphertext);
+        self.ctr(&j0).apply_keystream(0, &mut plaintext);
+
+        Ok(plaintext)
+    }
+
+    // J0 = IV || 0^31 || 1 for 96-bit IVs, GHASH(IV) otherwise
+    // Only seal can reach the panic, open checks for an empty IV first
+    fn pre_counter(&self, iv: &[Byte]) -> [Byte; 16]
+    {
+        if iv.is_empty() { panic!("Malformed IV (empty)"); }
+
+        match iv.len()
+        {
+            12 => {
+                let mut j0 = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+                j0[..12].copy_from_slice(iv);
+                j0
+            },
+            _ => ghash(self.h, &[], iv).to_block()
+        }
+    }
+
+    // Keystream from inc32(J0)
+    fn ctr(&self, j0: &[Byte; 16]) -> Ctr<&C>
+    {
+        let counter = u32::from_be_bytes([j0[12], j0[13], j0[14], j0[15]]) as u128;
+
+        Ctr::new(&self.cipher, &j0[..12], CounterLayout::NIST).with_counter(counter + 1)
+    }
+
+    fn tag(&self, j0: &[Byte; 16], aad: &[Byte], ciphertext: &[Byte]) -> Bytes
+    {
+        let mut mask = *j0;
+        self.cipher.encrypt_block(&mut mask);
+
+        let tag = (ghash(self.h, aad, ciphertext) + GF128::from_block(&mask)).to_block();
+
+        Bytes::from(&tag[..self.tag_size])
+    }
+}
+
+fn too_long(length: usize) -> bool
+{
+    length as u64 > MAX_LENGTH
+}
+
+// GHASH_H(A || 0^v || C || 0^u || [len(A)]64 || [len(C)]64)
+pub fn ghash(h: GF128, aad: &[Byte], data: &[Byte]) -> GF128
+{
+    let mut y = GF128::ZERO;
+
+    for input in [aad, data].iter()
+    {
+        for chunk in input.chunks(16)
+        {
+            let mut block = [0; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+
+            y = (y + GF128::from_block(&block)) * h;
+        }
+    }
+
+    let lengths = ((aad.len() as u128 * 8) << 64) | (data.len() as u128 * 8);
+
+    (y + GF128::from_block(&lengths.to_be_bytes())) * h
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{Gcm, ghash, too_long, MAX_LENGTH};
+    use crate::crypto::modes::AeadError;
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::Bytes;
+    use crate::traits::FromHex;
+
+    fn gcm() -> Gcm<Aes>
+    {
+        Gcm::new(Aes::new(&Key::new(&Bytes::from_hex("feffe9928665731c6d6a8f9467308308"))))
+    }
+
+    fn plaintext() -> Bytes
+    {
+        Bytes::from_hex(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72\
+             1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        )
+    }
+
+    fn aad() -> Bytes
+    {
+        Bytes::from_hex("feedfacedeadbeeffeedfacedeadbeefabaddad2")
+    }
+
+    #[test]
+    fn test_case_2()
+    {
+        let gcm = Gcm::new(Aes::new(&Key::new(&Bytes::from([0; 16]))));
+        let (ciphertext, tag) = gcm.seal(&[0; 12], &[], &[0; 16]);
+
+        assert_eq!(Bytes::from_hex("0388dace60b6a392f328c2b971b2fe78"), ciphertext);
+        assert_eq!(Bytes::from_hex("ab6e47d42cec13bdf53a67b21257bddf"), tag);
+        assert_eq!("66e94bd4ef8a2c3b884cfa59ca342b2e", gcm.hash_key().to_string());
+    }
+
+    #[test]
+    fn test_case_4()
+    {
+        // 96-bit IV and AAD
+        let iv = Bytes::from_hex("cafebabefacedbaddecaf888");
+        let (ciphertext, tag) = gcm().seal(&iv, &aad(), &plaintext());
+
+        assert_eq!(
+            Bytes::from_hex(
+                "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+                 21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091"
+            ),
+            ciphertext
+        );
+        assert_eq!(Bytes::from_hex("5bc94fbc3221a5db94fae95ae7121a47"), tag);
+        assert_eq!(Ok(plaintext()), gcm().open(&iv, &aad(), &ciphertext, &tag));
+    }
+
+    #[test]
+    fn test_case_6()
+    {
+        // 60-byte IV, hashed into the pre-counter block
+        let iv = Bytes::from_hex(
+            "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728\
+             c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b"
+        );
+        let (ciphertext, tag) = gcm().seal(&iv, &aad(), &plaintext());
+
+        assert_eq!(
+            Bytes::from_hex(
+                "8ce24998625615b603a033aca13fb894be9112a5c3a211a8ba262a3cca7e2ca7\
+                 01e4a9a4fba43c90ccdcb281d48c7c6fd62875d2aca417034c34aee5"
+            ),
+            ciphertext
+        );
+        assert_eq!(Bytes::from_hex("619cc5aefffe0bfa462af43c1699d050"), tag);
+        assert_eq!(Ok(plaintext()), gcm().open(&iv, &aad(), &ciphertext, &tag));
+    }
+
+    #[test]
+    fn truncated_tag()
+    {
+        // Test case 5, 64-bit IV, 32-bit tag
+        let gcm = gcm().with_tag_size(4);
+        let iv = Bytes::from_hex("cafebabefacedbad");
+        let (ciphertext, tag) = gcm.seal(&iv, &aad(), &plaintext());
+
+        assert_eq!(Bytes::from_hex("3612d2e7"), tag);
+        assert_eq!(Ok(plaintext()), gcm.open(&iv, &aad(), &ciphertext, &tag));
+        assert_eq!(
+            Err(AeadError::TagSize { size: 16, expected: 4 }),
+            gcm.open(&iv, &aad(), &ciphertext, &Bytes::from_hex("3612d2e79e3b0785561be14aaca2fccb"))
+        );
+    }
+
+    #[test]
+    fn forgery()
+    {
+        let iv = Bytes::from_hex("cafebabefacedbaddecaf888");
+        let (ciphertext, tag) = gcm().seal(&iv, &aad(), &plaintext());
+
+        let mut flipped = ciphertext.clone();
+        flipped[0] ^= 1;
+
+        let mut bad_tag = tag.clone();
+        bad_tag[15] ^= 0x80;
+
+        assert_eq!(Err(AeadError::InvalidTag), gcm().open(&iv, &aad(), &flipped, &tag));
+        assert_eq!(Err(AeadError::InvalidTag), gcm().open(&iv, &[], &ciphertext, &tag));
+        assert_eq!(Err(AeadError::InvalidTag), gcm().open(&iv, &aad(), &ciphertext, &bad_tag));
+        assert_eq!(Err(AeadError::InvalidTag), gcm().open(&[0; 12], &aad(), &ciphertext, &tag));
+    }
+
+    #[test]
+    fn empty_iv()
+    {
+        let (ciphertext, tag) = gcm().seal(&[0; 12], &aad(), &plaintext());
+
+        assert_eq!(Err(AeadError::NonceSize { size: 0, expected: 12 }), gcm().open(&[], &aad(), &ciphertext, &tag));
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed IV")]
+    fn seal_empty_iv()
+    {
+        gcm().seal(&[], &aad(), &plaintext());
+    }
+
+    #[test]
+    fn ghash_lengths()
+    {
+        // Test case 2: GHASH(H, {}, C)
+        let h = Gcm::new(Aes::new(&Key::new(&Bytes::from([0; 16])))).hash_key();
+        let c = Bytes::from_hex("0388dace60b6a392f328c2b971b2fe78");
+
+        assert_eq!("f38cbb1ad69223dcc3457ae5b6b0f885", ghash(h, &[], &c).to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed tag size")]
+    fn malformed_tag_size()
+    {
+        gcm().with_tag_size(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed tag size")]
+    fn unapproved_tag_size()
+    {
+        // 40-bit tags are not in SP 800-38D
+        gcm().with_tag_size(5);
+    }
+
+    #[test]
+    fn max_length()
+    {
+        assert_eq!(68719476704, MAX_LENGTH);
+        assert!(!too_long(MAX_LENGTH as usize));
+        assert!(too_long(MAX_LENGTH as usize + 1));
+    }
+}
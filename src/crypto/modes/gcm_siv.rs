@@ -0,0 +1,341 @@
+use crate::types::{Byte, Bytes, SecretBytes, ct_eq, wipe};
+use crate::crypto::{BlockCipher, Rekey};
+use crate::math::GF128;
+
+use super::AeadError;
+
+const NONCE_SIZE: usize = 12;
+
+// RFC 8452 6, 2^36 bytes of plaintext or AAD, the ciphertext limit of 2^36 + 16 includes the tag
+const MAX_LENGTH: u64 = 1 << 36;
+
+// Nonce-misuse resistant AEAD (RFC 8452), per-nonce keys are derived from the key-generating key
+// and the tag, a POLYVAL of the inputs, is also the initial counter block
+pub struct GcmSiv<C: Rekey>
+{
+    cipher: C
+}
+
+impl<C: Rekey> GcmSiv<C>
+{
+    // Key-generating cipher, 128-bit blocks and 128 or 256-bit keys (AES-128 or AES-256)
+    pub fn new(cipher: C) -> Self
+    {
+        if cipher.block_size() != 16
+        {
+            panic!("Malformed block cipher ({} bytes blocks, GCM-SIV needs 16)", cipher.block_size());
+        }
+
+        if cipher.key_size() != 16 && cipher.key_size() != 32
+        {
+            panic!("Malformed key (GCM-SIV keys are 128 or 256 bits)");
+        }
+
+        GcmSiv { cipher: cipher }
+    }
+
+    // Returns (ciphertext, tag), RFC 8452 sends ciphertext || tag
+    pub fn seal(&self, nonce: &[Byte], aad: &[Byte], plaintext: &[Byte]) -> (Bytes, Bytes)
+    {
+        if nonce.len() != NONCE_SIZE
+        {
+            panic!("Malformed nonce ({} != {})", nonce.len(), NONCE_SIZE);
+        }
+
+        if too_long(plaintext.len())
+        {
+            panic!("Malformed plaintext ({} > {} bytes)", plaintext.len(), MAX_LENGTH);
+        }
+
+        if too_long(aad.len())
+        {
+            panic!("Malformed associated data ({} > {} bytes)", aad.len(), MAX_LENGTH);
+        }
+
+        let (mut auth_key, cipher) = self.derive_keys(nonce);
+
+        let tag = tag(&cipher, &auth_key, nonce, aad, plaintext);
+        let mut ciphertext = Bytes::from(plaintext);
+
+        ctr(&cipher, &tag, &mut ciphertext);
+        wipe(&mut auth_key);
+
+        (ciphertext, Bytes::from(&tag[..]))
+    }
+
+    // Decrypting comes first, the plaintext is wiped if the tag does not match
+    pub fn open(&self, nonce: &[Byte], aad: &[Byte], ciphertext: &[Byte], tag: &[Byte]) -> Result<Bytes, AeadError>
+    {
+        if tag.len() != 16
+        {
+            return Err(AeadError::TagSize { size: tag.len(), expected: 16 });
+        }
+
+        if nonce.len() != NONCE_SIZE
+        {
+            return Err(AeadError::NonceSize { size: nonce.len(), expected: NONCE_SIZE });
+        }
+
+        for length in [ciphertext.len(), aad.len()]
+        {
+            if too_long(length)
+            {
+                return Err(AeadError::TooLong { length: length, max: MAX_LENGTH });
+            }
+        }
+
+        let (mut auth_key, cipher) = self.derive_keys(nonce);
+
+        let mut expected = [0; 16];
+        expected.copy_from_slice(tag);
+
+        let mut plaintext = Bytes::from(ciphertext);
+        ctr(&cipher, &expected, &mut plaintext);
+
+        let valid = ct_eq(&self::tag(&cipher, &auth_key, nonce, aad, &plaintext), tag);
+        wipe(&mut auth_key);
+
+        if !valid
+        {
+            wipe(&mut plaintext);
+            return Err(AeadError::InvalidTag);
+        }
+
+        Ok(plaintext)
+    }
+
+    // Halves of E(le32(i) || nonce), 2 for the POLYVAL key then 2 or 4 for the encryption key
+    // The nonce has already been checked
+    fn derive_keys(&self, nonce: &[Byte]) -> ([Byte; 16], C)
+    {
+        let blocks = 2 + self.cipher.key_size() / 8;
+        let mut material = SecretBytes::zeroed(8 * blocks);
+
+        for i in 0..blocks
+        {
+            let mut block = [0; 16];
+
+            block[..4].copy_from_slice(&(i as u32).to_le_bytes());
+            block[4..].copy_from_slice(nonce);
+
+            self.cipher.encrypt_block(&mut block);
+            material.expose_mut()[8*i..8*i + 8].copy_from_slice(&block[..8]);
+
+            wipe(&mut block);
+        }
+
+        let mut auth_key = [0; 16];
+        auth_key.copy_from_slice(&material.expose()[..16]);
+
+        let cipher = self.cipher.rekey(&SecretBytes::new(&material.expose()[16..]));
+
+        (auth_key, cipher)
+    }
+}
+
+fn too_long(length: usize) -> bool
+{
+    length as u64 > MAX_LENGTH
+}
+
+// POLYVAL_H(S) xor nonce, top bit cleared, encrypted
+fn tag<C: BlockCipher>(cipher: &C, auth_key: &[Byte; 16], nonce: &[Byte], aad: &[Byte], plaintext: &[Byte]) -> [Byte; 16]
+{
+    let mut input = Bytes::new();
+
+    for data in [aad, plaintext].iter()
+    {
+        input.extend(data.iter());
+        input.resize(input.len().div_ceil(16) * 16, 0);
+    }
+
+    input.extend((aad.len() as u64 * 8).to_le_bytes().iter());
+    input.extend((plaintext.len() as u64 * 8).to_le_bytes().iter());
+
+    let mut s = polyval(auth_key, &input);
+    wipe(&mut input);
+
+    for i in 0..12 { s[i] ^= nonce[i]; }
+    s[15] &= 0x7f;
+
+    cipher.encrypt_block(&mut s);
+
+    s
+}
+
+// Counter block is the tag with its top bit set, 32-bit little-endian counter in the first word
+fn ctr<C: BlockCipher>(cipher: &C, tag: &[Byte; 16], data: &mut [Byte])
+{
+    let mut block = *tag;
+    block[15] |= 0x80;
+
+    let initial = u32::from_le_bytes([block[0], block[1], block[2], block[3]]);
+    let mut stream = vec![0; data.len().div_ceil(16) * 16];
+
+    for (i, counter) in stream.chunks_exact_mut(16).enumerate()
+    {
+        block[..4].copy_from_slice(&initial.wrapping_add(i as u32).to_le_bytes());
+        counter.copy_from_slice(&block);
+    }
+
+    cipher.encrypt_blocks(&mut stream);
+
+    for (b, k) in data.iter_mut().zip(stream.iter()) { *b ^= k; }
+}
+
+// POLYVAL(H, X1, ..., Xn) = ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)), ByteReverse(X1), ...))
+// (RFC 8452 appendix A), data must be a multiple of 16 bytes
+pub fn polyval(h: &[Byte; 16], data: &[Byte]) -> [Byte; 16]
+{
+    if !data.len().is_multiple_of(16) { panic!("Malformed data ({} is not a multiple of 16)", data.len()); }
+
+    let h = GF128::from_block(&reversed(h)) * GF128::from_poly(2);
+    let mut y = GF128::ZERO;
+
+    for block in data.chunks_exact(16)
+    {
+        let mut x = [0; 16];
+        x.copy_from_slice(block);
+
+        y = (y + GF128::from_block(&reversed(&x))) * h;
+    }
+
+    reversed(&y.to_block())
+}
+
+fn reversed(block: &[Byte; 16]) -> [Byte; 16]
+{
+    let mut result = *block;
+    result.reverse();
+
+    result
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{GcmSiv, polyval, too_long, MAX_LENGTH};
+    use crate::crypto::aes::{Aes, Key};
+    use crate::crypto::modes::{AeadError, Gcm};
+    use crate::types::{Byte, Bytes};
+    use crate::traits::FromHex;
+
+    fn aes(size: usize) -> Aes
+    {
+        let mut key = vec![0; size];
+        key[0] = 1;
+
+        Aes::new(&Key::new(&key))
+    }
+
+    fn nonce() -> Bytes
+    {
+        Bytes::from_hex("030000000000000000000000")
+    }
+
+    #[test]
+    fn polyval_example()
+    {
+        // RFC 8452 appendix A
+        let mut h = [0; 16];
+        h.copy_from_slice(&Bytes::from_hex("25629347589242761d31f826ba4b757b"));
+
+        let x = Bytes::from_hex("4f4f95668c83dfb6401762bb2d01a262d1a24ddd2721d006bbe45f20d3c9f362");
+
+        assert_eq!(Bytes::from_hex("f7a3b47b846119fae5b7866cf5e5b77e"), Bytes::from(&polyval(&h, &x)[..]));
+    }
+
+    #[test]
+    fn aes_128()
+    {
+        // RFC 8452 C.1
+        let siv = GcmSiv::new(aes(16));
+
+        let (ciphertext, tag) = siv.seal(&nonce(), &[], &[]);
+        assert_eq!(Bytes::new(), ciphertext);
+        assert_eq!(Bytes::from_hex("dc20e2d83f25705bb49e439eca56de25"), tag);
+
+        let plaintext = Bytes::from_hex("0100000000000000");
+        let (ciphertext, tag) = siv.seal(&nonce(), &[], &plaintext);
+
+        assert_eq!(Bytes::from_hex("b5d839330ac7b786"), ciphertext);
+        assert_eq!(Bytes::from_hex("578782fff6013b815b287c22493a364c"), tag);
+        assert_eq!(Ok(plaintext), siv.open(&nonce(), &[], &ciphertext, &tag));
+    }
+
+    #[test]
+    fn aad()
+    {
+        // RFC 8452 C.1 and C.2, 20-byte plaintext with AAD
+        let aad = Bytes::from_hex("01");
+        let plaintext = Bytes::from_hex("0200000000000000000000000000000003000000");
+
+        let cases = [
+            (16, "c76072b05ac917351e56fc8493253b79bb38090f", "0d15034aedd904d492a37fad0d930edb"),
+            (32, "2ac1bc2e3d41e827c7c307cb8fd7548438d7b728", "3f7102190664acef1509029af7adcab5")
+        ];
+
+        for (size, expected, expected_tag) in cases
+        {
+            let siv = GcmSiv::new(aes(size));
+            let (ciphertext, tag) = siv.seal(&nonce(), &aad, &plaintext);
+
+            assert_eq!(Bytes::from_hex(expected), ciphertext);
+            assert_eq!(Bytes::from_hex(expected_tag), tag);
+            assert_eq!(Ok(plaintext.clone()), siv.open(&nonce(), &aad, &ciphertext, &tag));
+        }
+    }
+
+    #[test]
+    fn forgery()
+    {
+        let siv = GcmSiv::new(aes(32));
+        let (ciphertext, tag) = siv.seal(&nonce(), b"header", b"attack at dawn");
+
+        let mut flipped = ciphertext.clone();
+        flipped[3] ^= 1;
+
+        assert_eq!(Err(AeadError::InvalidTag), siv.open(&nonce(), b"header", &flipped, &tag));
+        assert_eq!(Err(AeadError::InvalidTag), siv.open(&nonce(), b"footer", &ciphertext, &tag));
+        assert_eq!(Err(AeadError::InvalidTag), siv.open(&[0; 12], b"header", &ciphertext, &tag));
+        assert_eq!(Err(AeadError::NonceSize { size: 8, expected: 12 }), siv.open(&[0; 8], b"header", &ciphertext, &tag));
+    }
+
+    #[test]
+    fn nonce_reuse()
+    {
+        let first  = Bytes::from("attack at dawn!!");
+        let second = Bytes::from("attack at dusk!!");
+        let xor = |a: &[Byte], b: &[Byte]| a.iter().zip(b.iter()).map( |(x, y)| x ^ y ).collect::<Vec<_>>();
+
+        // GCM under a reused nonce: same keystream, the xor of the plaintexts leaks
+        let gcm = Gcm::new(aes(16));
+        let (c1, _) = gcm.seal(&nonce(), &[], &first);
+        let (c2, _) = gcm.seal(&nonce(), &[], &second);
+
+        assert_eq!(xor(&first, &second), xor(&c1, &c2));
+
+        // GCM-SIV: the keystream depends on the plaintext through the tag
+        let siv = GcmSiv::new(aes(16));
+        let (c1, t1) = siv.seal(&nonce(), &[], &first);
+        let (c2, t2) = siv.seal(&nonce(), &[], &second);
+
+        assert_ne!(t1, t2);
+        assert_ne!(xor(&first, &second), xor(&c1, &c2));
+    }
+
+    #[test]
+    fn max_length()
+    {
+        assert_eq!(68719476736, MAX_LENGTH);
+        assert!(!too_long(MAX_LENGTH as usize));
+        assert!(too_long(MAX_LENGTH as usize + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed key")]
+    fn malformed_key()
+    {
+        GcmSiv::new(aes(24));
+    }
+}
@@ -0,0 +1,79 @@
+mod ecb;
+mod cbc;
+mod ctr;
+mod cfb;
+mod ofb;
+mod gcm;
+mod cmac;
+mod siv;
+mod gcm_siv;
+mod error;
+
+pub use ecb::Ecb;
+pub use cbc::Cbc;
+pub use ctr::{Ctr, CounterLayout, Endianness};
+pub use cfb::{Cfb, CfbStream, Segment};
+pub use ofb::{Ofb, OfbStream};
+pub use gcm::{Gcm, ghash};
+pub use cmac::Cmac;
+pub use siv::Siv;
+pub use gcm_siv::{GcmSiv, polyval};
+pub use error::AeadError;
+
+use crate::types::{Byte, Bytes};
+use crate::iterators::BlockIterator;
+
+// Block cipher mode of operation, taking care of the padding if it needs one
+pub trait Mode
+{
+    fn encrypt(&self, plaintext: &[Byte]) -> Bytes;
+    fn decrypt(&self, ciphertext: &[Byte]) -> Bytes;
+}
+
+// PKCS#7
+fn pad(data: &[Byte], block_size: usize) -> Bytes
+{
+    let mut padded = Bytes::new();
+
+    for block in BlockIterator::new(data, block_size).with_padding()
+    {
+        padded.extend(block);
+    }
+
+    padded
+}
+
+fn unpad(data: &[Byte], block_size: usize) -> Bytes
+{
+    BlockIterator::new(data, block_size).remove_padding()
+}
+
+#[cfg(test)]
+pub(crate) mod tests
+{
+    use crate::crypto::BlockCipher;
+    use crate::types::Byte;
+
+    // Toy 8-byte block cipher: xor with the key then rotate the block by one byte
+    pub struct Toy(pub [Byte; 8]);
+
+    impl BlockCipher for Toy
+    {
+        fn block_size(&self) -> usize
+        {
+            8
+        }
+
+        fn encrypt_block(&self, block: &mut [Byte])
+        {
+            for i in 0..8 { block[i] ^= self.0[i]; }
+            block.rotate_left(1);
+        }
+
+        fn decrypt_block(&self, block: &mut [Byte])
+        {
+            block.rotate_right(1);
+            for i in 0..8 { block[i] ^= self.0[i]; }
+        }
+    }
+}
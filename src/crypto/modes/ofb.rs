@@ -0,0 +1,131 @@
+use crate::types::{Byte, Bytes};
+use crate::crypto::BlockCipher;
+
+use super::Mode;
+
+// Stream mode, no padding, the keystream only depends on the key and the IV
+pub struct Ofb<C: BlockCipher>
+{
+    cipher: C,
+    iv:     Bytes
+}
+
+impl<C: BlockCipher> Ofb<C>
+{
+    pub fn new(cipher: C, iv: &[Byte]) -> Self
+    {
+        if iv.len() != cipher.block_size()
+        {
+            panic!("Malformed IV ({} != {})", iv.len(), cipher.block_size());
+        }
+
+        Ofb { cipher: cipher, iv: Bytes::from(iv) }
+    }
+
+    pub fn stream(&self) -> OfbStream<'_, C>
+    {
+        OfbStream {
+            cipher:   &self.cipher,
+            register: self.iv.clone(),
+            position: self.iv.len()
+        }
+    }
+}
+
+impl<C: BlockCipher> Mode for Ofb<C>
+{
+    fn encrypt(&self, plaintext: &[Byte]) -> Bytes
+    {
+        let mut data = Bytes::from(plaintext);
+
+        self.stream().update(&mut data);
+
+        data
+    }
+
+    fn decrypt(&self, ciphertext: &[Byte]) -> Bytes
+    {
+        self.encrypt(ciphertext)
+    }
+}
+
+// Incremental OFB, data can be given in chunks of any size
+pub struct OfbStream<'a, C: BlockCipher>
+{
+    cipher:   &'a C,
+    register: Bytes,
+    position: usize
+}
+
+impl<'a, C: BlockCipher> OfbStream<'a, C>
+{
+    // Xor data with the keystream in place, this both encrypts and decrypts
+    pub fn update(&mut self, data: &mut [Byte])
+    {
+        for byte in data.iter_mut()
+        {
+            if self.position == self.register.len()
+            {
+                self.cipher.encrypt_block(&mut self.register);
+                self.position = 0;
+            }
+
+            *byte ^= self.register[self.position];
+            self.position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Ofb;
+    use crate::crypto::modes::Mode;
+    use crate::crypto::modes::tests::Toy;
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::Bytes;
+    use crate::traits::FromHex;
+
+    #[test]
+    fn sp800_38a()
+    {
+        // F.4.1 and F.4.2 OFB-AES128
+        let key = Key::new(&Bytes::from_hex("2b7e151628aed2a6abf7158809cf4f3c"));
+        let ofb = Ofb::new(Aes::new(&key), &Bytes::from_hex("000102030405060708090a0b0c0d0e0f"));
+
+        let plaintext = Bytes::from_hex(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+        );
+        let ciphertext = Bytes::from_hex(
+            "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed825\
+             9740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e"
+        );
+
+        assert_eq!(ciphertext, ofb.encrypt(&plaintext));
+        assert_eq!(plaintext, ofb.decrypt(&ciphertext));
+    }
+
+    #[test]
+    fn stream()
+    {
+        let ofb = Ofb::new(Toy([0x2a; 8]), &[0x01; 8]);
+        let plaintext = Bytes::from("OFB as a stream, in chunks that do not line up with blocks");
+        let expected = ofb.encrypt(&plaintext);
+
+        let mut data = plaintext.clone();
+        let mut stream = ofb.stream();
+
+        for chunk in data.chunks_mut(3) { stream.update(chunk); }
+
+        assert_eq!(expected, data);
+        assert_eq!(plaintext, ofb.decrypt(&data));
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed IV")]
+    fn malformed_iv()
+    {
+        Ofb::new(Toy([0x2a; 8]), &[0x01; 4]);
+    }
+}
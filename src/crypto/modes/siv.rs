@@ -0,0 +1,228 @@
+use crate::types::{Byte, Bytes, ct_eq, wipe};
+use crate::crypto::BlockCipher;
+
+use super::{Ctr, CounterLayout, Endianness, Cmac, AeadError, cmac::dbl};
+
+// S2V doubles once per component, RFC 5297 caps the vector at 127 strings with the plaintext
+const MAX_COMPONENTS: usize = 126;
+
+// Deterministic AEAD (RFC 5297), the tag is a MAC of the inputs and also the CTR IV,
+// so a repeated nonce only reveals that the same message was sent twice
+pub struct Siv<C: BlockCipher>
+{
+    mac:    Cmac<C>,
+    cipher: C
+}
+
+impl<C: BlockCipher> Siv<C>
+{
+    // The key is K1 || K2, K1 for S2V and K2 for CTR
+    pub fn new(mac_cipher: C, ctr_cipher: C) -> Self
+    {
+        if ctr_cipher.block_size() != 16
+        {
+            panic!("Malformed block cipher ({} bytes blocks, SIV needs 16)", ctr_cipher.block_size());
+        }
+
+        Siv { mac: Cmac::new(mac_cipher), cipher: ctr_cipher }
+    }
+
+    // Returns (ciphertext, V), RFC 5297 sends V || ciphertext
+    // A nonce, if any, is the last associated data component
+    pub fn seal(&self, aad: &[&[Byte]], plaintext: &[Byte]) -> (Bytes, Bytes)
+    {
+        let v = self.s2v(aad, plaintext);
+        let mut ciphertext = Bytes::from(plaintext);
+
+        self.ctr(&v).apply_keystream(0, &mut ciphertext);
+
+        (ciphertext, Bytes::from(&v[..]))
+    }
+
+    // Decrypting comes first, the plaintext is wiped if V does not match
+    pub fn open(&self, aad: &[&[Byte]], ciphertext: &[Byte], tag: &[Byte]) -> Result<Bytes, AeadError>
+    {
+        if tag.len() != 16
+        {
+            return Err(AeadError::TagSize { size: tag.len(), expected: 16 });
+        }
+
+        if aad.len() > MAX_COMPONENTS
+        {
+            return Err(AeadError::TooManyComponents { count: aad.len(), max: MAX_COMPONENTS });
+        }
+
+        let mut v = [0; 16];
+        v.copy_from_slice(tag);
+
+        let mut plaintext = Bytes::from(ciphertext);
+        self.ctr(&v).apply_keystream(0, &mut plaintext);
+
+        if !ct_eq(&self.s2v(aad, &plaintext), tag)
+        {
+            wipe(&mut plaintext);
+            return Err(AeadError::InvalidTag);
+        }
+
+        Ok(plaintext)
+    }
+
+    // S2V over the associated data components then the plaintext
+    pub fn s2v(&self, aad: &[&[Byte]], plaintext: &[Byte]) -> [Byte; 16]
+    {
+        if aad.len() > MAX_COMPONENTS
+        {
+            panic!("Malformed associated data ({} > {} components)", aad.len(), MAX_COMPONENTS);
+        }
+
+        let mut d = self.mac.mac(&[0; 16]);
+
+        for component in aad.iter()
+        {
+            let m = self.mac.mac(component);
+
+            d = dbl(&d);
+            for i in 0..16 { d[i] ^= m[i]; }
+        }
+
+        let mut t = Bytes::from(plaintext);
+
+        if t.len() >= 16
+        {
+            // xorend
+            let start = t.len() - 16;
+            for i in 0..16 { t[start + i] ^= d[i]; }
+        }
+        else
+        {
+            let d = dbl(&d);
+
+            t.push(0x80);
+            t.resize(16, 0);
+
+            for i in 0..16 { t[i] ^= d[i]; }
+        }
+
+        let v = self.mac.mac(&t);
+        wipe(&mut t);
+
+        v
+    }
+
+    // Q = V with bits 63 and 31 cleared, then a 128-bit big-endian counter
+    fn ctr(&self, v: &[Byte; 16]) -> Ctr<&C>
+    {
+        let mut q = *v;
+
+        q[8]  &= 0x7f;
+        q[12] &= 0x7f;
+
+        let layout = CounterLayout { counter_size: 16, endianness: Endianness::Big };
+
+        Ctr::new(&self.cipher, &[], layout).with_counter(u128::from_be_bytes(q))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Siv;
+    use crate::crypto::modes::{AeadError, Mode, Ctr, CounterLayout};
+    use crate::crypto::aes::{Aes, Key};
+    use crate::types::{Bytes, Byte};
+    use crate::traits::FromHex;
+
+    fn siv(key: &str) -> Siv<Aes>
+    {
+        let key = Bytes::from_hex(key);
+
+        Siv::new(Aes::new(&Key::new(&key[..16])), Aes::new(&Key::new(&key[16..])))
+    }
+
+    #[test]
+    fn deterministic()
+    {
+        // RFC 5297 A.1
+        let siv = siv("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+        let aad = Bytes::from_hex("101112131415161718191a1b1c1d1e1f2021222324252627");
+        let plaintext = Bytes::from_hex("112233445566778899aabbccddee");
+
+        let (ciphertext, v) = siv.seal(&[&aad], &plaintext);
+
+        assert_eq!(Bytes::from_hex("85632d07c6e8f37f950acd320a2ecc93"), v);
+        assert_eq!(Bytes::from_hex("40c02b9690c4dc04daef7f6afe5c"), ciphertext);
+        assert_eq!(Ok(plaintext), siv.open(&[&aad], &ciphertext, &v));
+    }
+
+    #[test]
+    fn nonce_based()
+    {
+        // RFC 5297 A.2, the nonce is the last component
+        let siv = siv("7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f");
+        let ad1 = Bytes::from_hex("00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100");
+        let ad2 = Bytes::from_hex("102030405060708090a0");
+        let nonce = Bytes::from_hex("09f911029d74e35bd84156c5635688c0");
+        let plaintext = Bytes::from("this is some plaintext to encrypt using SIV-AES");
+
+        let (ciphertext, v) = siv.seal(&[&ad1, &ad2, &nonce], &plaintext);
+
+        assert_eq!(Bytes::from_hex("7bdb6e3b432667eb06f4d14bff2fbd0f"), v);
+        assert_eq!(
+            Bytes::from_hex(
+                "cb900f2fddbe404326601965c889bf17dba77ceb094fa663b7a3f748ba8af829\
+                 ea64ad544a272e9c485b62a3fd5c0d"
+            ),
+            ciphertext
+        );
+        assert_eq!(Ok(plaintext), siv.open(&[&ad1, &ad2, &nonce], &ciphertext, &v));
+    }
+
+    #[test]
+    fn forgery()
+    {
+        let siv = siv("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+        let (ciphertext, v) = siv.seal(&[b"header"], b"attack at dawn");
+
+        let mut flipped = ciphertext.clone();
+        flipped[0] ^= 1;
+
+        assert_eq!(Err(AeadError::InvalidTag), siv.open(&[b"header"], &flipped, &v));
+        assert_eq!(Err(AeadError::InvalidTag), siv.open(&[b"footer"], &ciphertext, &v));
+        assert_eq!(Err(AeadError::InvalidTag), siv.open(&[], &ciphertext, &v));
+        assert_eq!(Err(AeadError::TagSize { size: 8, expected: 16 }), siv.open(&[b"header"], &ciphertext, &v[..8]));
+    }
+
+    #[test]
+    fn too_many_components()
+    {
+        let siv = siv("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+        let (ciphertext, v) = siv.seal(&[b"header"], b"attack at dawn");
+        let aad: Vec<&[Byte]> = vec![b"header"; 127];
+
+        assert_eq!(Err(AeadError::TooManyComponents { count: 127, max: 126 }), siv.open(&aad, &ciphertext, &v));
+    }
+
+    #[test]
+    fn nonce_reuse()
+    {
+        let key = Key::new(b"YELLOW SUBMARINE");
+        let nonce: &[Byte] = &[0; 16];
+        let first  = Bytes::from("attack at dawn!!");
+        let second = Bytes::from("attack at dusk!!");
+
+        // With CTR a reused nonce gives the xor of the plaintexts away
+        let ctr = Ctr::new(Aes::new(&key), &nonce[..8], CounterLayout::CRYPTOPALS);
+        let leak = ctr.encrypt(&first).iter().zip(ctr.encrypt(&second).iter()).map( |(a, b)| a ^ b ).collect::<Vec<_>>();
+
+        assert_eq!(first.iter().zip(second.iter()).map( |(a, b)| a ^ b ).collect::<Vec<_>>(), leak);
+
+        // With SIV the IV changes with the plaintext, only equal messages are linkable
+        let siv = Siv::new(Aes::new(&key), Aes::new(&key));
+        let (c1, v1) = siv.seal(&[nonce], &first);
+        let (c2, v2) = siv.seal(&[nonce], &second);
+
+        assert_ne!(v1, v2);
+        assert_ne!(leak, c1.iter().zip(c2.iter()).map( |(a, b)| a ^ b ).collect::<Vec<_>>());
+        assert_eq!((c1, v1), siv.seal(&[nonce], &first));
+    }
+}
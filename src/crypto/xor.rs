@@ -0,0 +1,41 @@
+use crate::types::{Bytes, Cycle};
+
+pub fn fixed(a: &Bytes, b: &Bytes) -> Bytes
+{
+    a ^ b
+}
+
+pub fn repeating_key(text: &Bytes, key: &Bytes) -> Bytes
+{
+    text ^ Cycle(key)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::traits::FromHex;
+
+    #[test]
+    fn fixed()
+    {
+        assert_eq!(
+            Bytes::from_hex("072a2b2e287922352a3e28"),
+            super::fixed(&Bytes::from("Lorem ipsum"), &Bytes::from("KEYKEYKEYKE"))
+        );
+    }
+
+    #[test]
+    fn repeating_key()
+    {
+        assert_eq!(
+            Bytes::from_hex("0b3637272a2b2e63622c2e69692a23693a2a3c6324202d623d63343c2a26226324272765272a282b2f20"),
+            super::repeating_key(&Bytes::from("Burning 'em, if you ain't quick and nimble"), &Bytes::from("ICE"))
+        );
+
+        assert_eq!(
+            Bytes::from_hex("0063222663263b223f30633221262b690a652126243b632469203c24212425"),
+            super::repeating_key(&Bytes::from("I go crazy when I hear a cymbal"), &Bytes::from("ICE"))
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,285 @@
+use std::io::{self, Read, Write};
+
+use crate::types::{Bytes, Base64, Base64Decoder};
+use super::{BUFFER_SIZE, invalid_data};
+
+// Decode a base64 stream, positions in errors are byte offsets in the stream
+pub struct Base64Reader<R: Read>
+{
+    inner:    R,
+    decoder:  Base64Decoder,
+    input:    Vec<u8>,
+    output:   Bytes,
+    offset:   usize,
+    position: usize,
+    eof:      bool
+}
+
+impl<R: Read> Base64Reader<R>
+{
+    pub fn new(inner: R) -> Self
+    {
+        Base64Reader {
+            inner:    inner,
+            decoder:  Base64Decoder::new(Base64::STANDARD),
+            input:    vec![0x0; BUFFER_SIZE],
+            output:   Bytes::with_capacity(BUFFER_SIZE),
+            offset:   0,
+            position: 0,
+            eof:      false
+        }
+    }
+
+    pub fn with_engine(mut self, engine: Base64) -> Self
+    {
+        if self.position != 0 { panic!("Engine must be set before reading"); }
+
+        self.decoder = Base64Decoder::new(engine);
+        self
+    }
+
+    pub fn into_inner(self) -> R
+    {
+        self.inner
+    }
+
+    fn fill(&mut self) -> io::Result<()>
+    {
+        self.output.clear();
+        self.offset = 0;
+
+        let n = self.inner.read(&mut self.input)?;
+
+        if n == 0
+        {
+            self.eof = true;
+            return self.decoder.finish(&mut self.output).map_err(invalid_data);
+        }
+
+        for &b in &self.input[..n]
+        {
+            self.decoder.push(self.position, b as char, &mut self.output).map_err(invalid_data)?;
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Base64Reader<R>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+    {
+        while self.offset == self.output.len()
+        {
+            if self.eof { return Ok(0); }
+
+            self.fill()?;
+        }
+
+        let n = buf.len().min(self.output.len() - self.offset);
+
+        buf[..n].copy_from_slice(&self.output[self.offset..self.offset + n]);
+        self.offset += n;
+
+        Ok(n)
+    }
+}
+
+// Encode bytes as base64, call finish to write the trailing group and padding
+pub struct Base64Writer<W: Write>
+{
+    inner:   Option<W>,
+    engine:  Base64,
+    pending: Bytes,
+    column:  usize
+}
+
+impl<W: Write> Base64Writer<W>
+{
+    pub fn new(inner: W) -> Self
+    {
+        Base64Writer {
+            inner:   Some(inner),
+            engine:  Base64::STANDARD,
+            pending: Bytes::with_capacity(2),
+            column:  0
+        }
+    }
+
+    pub fn with_engine(mut self, engine: Base64) -> Self
+    {
+        self.engine = engine;
+        self
+    }
+
+    pub fn finish(mut self) -> io::Result<W>
+    {
+        self.write_pending()?;
+
+        let mut inner = self.inner.take().unwrap();
+        inner.flush()?;
+
+        Ok(inner)
+    }
+
+    fn write_pending(&mut self) -> io::Result<()>
+    {
+        let pending = std::mem::take(&mut self.pending);
+
+        self.write_encoded(&pending)
+    }
+
+    fn write_encoded(&mut self, bytes: &[u8]) -> io::Result<()>
+    {
+        let encoded = self.engine.without_line_wrap().encode(bytes);
+        let inner   = self.inner.as_mut().unwrap();
+
+        let (width, line_ending) = match self.engine.line_wrap()
+        {
+            Some(wrap) => wrap,
+            None => return inner.write_all(encoded.as_bytes())
+        };
+
+        let mut encoded = encoded.as_bytes();
+
+        while !encoded.is_empty()
+        {
+            if self.column == width
+            {
+                inner.write_all(line_ending.as_bytes())?;
+                self.column = 0;
+            }
+
+            let n = encoded.len().min(width - self.column);
+
+            inner.write_all(&encoded[..n])?;
+            self.column += n;
+            encoded = &encoded[n..];
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for Base64Writer<W>
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    {
+        let n = buf.len().min(BUFFER_SIZE);
+
+        self.pending.extend(&buf[..n]);
+
+        // Only full 3-byte groups can be encoded without padding
+        let full = self.pending.len() - self.pending.len() % 3;
+        let rest = self.pending.split_off(full);
+        let full = std::mem::replace(&mut self.pending, rest);
+
+        self.write_encoded(&full)?;
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()>
+    {
+        self.inner.as_mut().unwrap().flush()
+    }
+}
+
+impl<W: Write> Drop for Base64Writer<W>
+{
+    fn drop(&mut self)
+    {
+        if self.inner.is_some()
+        {
+            // Errors can't be reported on drop, use finish to handle them
+            let _ = self.write_pending();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::types::CodecError;
+    use crate::io::codec_error;
+    use crate::traits::ToBase64;
+
+    // Feed the inner reader one byte at a time
+    struct Trickle<'a>(&'a [u8]);
+
+    impl<'a> Read for Trickle<'a>
+    {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+        {
+            if self.0.is_empty() || buf.is_empty() { return Ok(0); }
+
+            buf[0] = self.0[0];
+            self.0 = &self.0[1..];
+
+            Ok(1)
+        }
+    }
+
+    fn data() -> Bytes
+    {
+        (0..10_000).map( |i| (i * 7 % 251) as u8 ).collect()
+    }
+
+    #[test]
+    fn reader()
+    {
+        let data    = data();
+        let encoded = Base64::MIME.encode(&data);
+
+        let mut decoded = vec![];
+        Base64Reader::new(encoded.as_bytes()).read_to_end(&mut decoded).unwrap();
+
+        assert_eq!(data, decoded);
+
+        let mut decoded = vec![];
+        Base64Reader::new(Trickle(encoded.as_bytes())).read_to_end(&mut decoded).unwrap();
+
+        assert_eq!(data, decoded);
+    }
+
+    #[test]
+    fn reader_error()
+    {
+        let mut decoded = vec![];
+        let error = Base64Reader::new("VGVz\nd*==".as_bytes())
+            .read_to_end(&mut decoded)
+            .unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert_eq!(
+            Some(&CodecError::InvalidChar { position: 6, character: '*' }),
+            codec_error(&error)
+        );
+    }
+
+    #[test]
+    fn writer()
+    {
+        let data = data();
+
+        let mut writer = Base64Writer::new(vec![]);
+
+        for chunk in data.chunks(7)
+        {
+            writer.write_all(chunk).unwrap();
+        }
+
+        assert_eq!(data.to_base64(), String::from_utf8(writer.finish().unwrap()).unwrap());
+
+        let mut writer = Base64Writer::new(vec![]).with_engine(Base64::PEM);
+
+        for chunk in data.chunks(50)
+        {
+            writer.write_all(chunk).unwrap();
+        }
+
+        assert_eq!(Base64::PEM.encode(&data), String::from_utf8(writer.finish().unwrap()).unwrap());
+    }
+}
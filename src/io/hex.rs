@@ -0,0 +1,179 @@
+use std::io::{self, Read, Write};
+
+use crate::types::{Bytes, CodecError};
+use crate::traits::ToHex;
+use super::{BUFFER_SIZE, invalid_data};
+
+// Decode a hex stream (whitespace is skipped), positions in errors are byte offsets in the stream
+pub struct HexReader<R: Read>
+{
+    inner:    R,
+    input:    Vec<u8>,
+    output:   Bytes,
+    offset:   usize,
+    nibble:   Option<u8>,
+    position: usize,
+    length:   usize,
+    eof:      bool
+}
+
+impl<R: Read> HexReader<R>
+{
+    pub fn new(inner: R) -> Self
+    {
+        HexReader {
+            inner:    inner,
+            input:    vec![0x0; BUFFER_SIZE],
+            output:   Bytes::with_capacity(BUFFER_SIZE / 2),
+            offset:   0,
+            nibble:   None,
+            position: 0,
+            length:   0,
+            eof:      false
+        }
+    }
+
+    pub fn into_inner(self) -> R
+    {
+        self.inner
+    }
+
+    fn fill(&mut self) -> io::Result<()>
+    {
+        self.output.clear();
+        self.offset = 0;
+
+        let n = self.inner.read(&mut self.input)?;
+
+        if n == 0
+        {
+            self.eof = true;
+
+            if self.nibble.is_some()
+            {
+                return Err(invalid_data(CodecError::OddLength { length: self.length }));
+            }
+
+            return Ok(());
+        }
+
+        for &b in &self.input[..n]
+        {
+            let c = b as char;
+            let position = self.position;
+
+            self.position += 1;
+
+            if c.is_ascii_whitespace() { continue; }
+
+            let n = match c.to_digit(16)
+            {
+                Some(n) => n as u8,
+                None => return Err(invalid_data(CodecError::InvalidChar { position: position, character: c }))
+            };
+
+            self.length += 1;
+
+            match self.nibble.take()
+            {
+                Some(high) => self.output.push(high << 4 | n),
+                None => self.nibble = Some(n)
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for HexReader<R>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+    {
+        while self.offset == self.output.len()
+        {
+            if self.eof { return Ok(0); }
+
+            self.fill()?;
+        }
+
+        let n = buf.len().min(self.output.len() - self.offset);
+
+        buf[..n].copy_from_slice(&self.output[self.offset..self.offset + n]);
+        self.offset += n;
+
+        Ok(n)
+    }
+}
+
+pub struct HexWriter<W: Write>
+{
+    inner: W
+}
+
+impl<W: Write> HexWriter<W>
+{
+    pub fn new(inner: W) -> Self
+    {
+        HexWriter { inner: inner }
+    }
+
+    pub fn into_inner(self) -> W
+    {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for HexWriter<W>
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    {
+        let n = buf.len().min(BUFFER_SIZE);
+
+        self.inner.write_all(Bytes::from(&buf[..n]).to_hex().as_bytes())?;
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()>
+    {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::io::codec_error;
+
+    #[test]
+    fn round_trip()
+    {
+        let data: Bytes = (0..10_000).map( |i| (i % 256) as u8 ).collect();
+
+        let mut writer = HexWriter::new(vec![]);
+        writer.write_all(&data).unwrap();
+
+        let encoded = writer.into_inner();
+
+        assert_eq!(data.to_hex().as_bytes(), &encoded[..]);
+
+        let mut decoded = vec![];
+        HexReader::new(&encoded[..]).read_to_end(&mut decoded).unwrap();
+
+        assert_eq!(data, decoded);
+    }
+
+    #[test]
+    fn reader_errors()
+    {
+        let mut decoded = vec![];
+        let error = HexReader::new("0a1b\n2c3".as_bytes()).read_to_end(&mut decoded).unwrap_err();
+
+        assert_eq!(Some(&CodecError::OddLength { length: 7 }), codec_error(&error));
+
+        let error = HexReader::new("0a1b\n2x".as_bytes()).read_to_end(&mut decoded).unwrap_err();
+
+        assert_eq!(Some(&CodecError::InvalidChar { position: 6, character: 'x' }), codec_error(&error));
+    }
+}
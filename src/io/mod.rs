@@ -0,0 +1,23 @@
+mod base64;
+mod hex;
+
+pub use self::base64::{Base64Reader, Base64Writer};
+pub use self::hex::{HexReader, HexWriter};
+
+use std::io;
+
+use crate::types::CodecError;
+
+// Size of the chunks read from (or written to) the inner stream
+const BUFFER_SIZE: usize = 4096;
+
+fn invalid_data(e: CodecError) -> io::Error
+{
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+// Extract the codec error (if any) carried by an io::Error returned by the readers
+pub fn codec_error(e: &io::Error) -> Option<&CodecError>
+{
+    e.get_ref().and_then( |inner| inner.downcast_ref::<CodecError>() )
+}
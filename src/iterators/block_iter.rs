@@ -1,11 +1,11 @@
-use crate::lib::types::{Byte, ByteVec};
+use crate::types::{Byte, Bytes};
 
 use std::iter;
 use std::cmp;
 
 pub struct BlockIterator
 {
-    values:     ByteVec,
+    values:     Bytes,
     block_size: usize,
     index:      usize,
     padding:    bool
@@ -16,7 +16,7 @@ impl BlockIterator
     pub fn new(values: &[Byte], block_size: usize) -> Self
     {
         BlockIterator {
-            values:     values.to_vec(),
+            values:     Bytes::from(values),
             block_size: block_size,
             index:      0,
             padding:    false
@@ -35,7 +35,7 @@ impl BlockIterator
         self
     }
 
-    pub fn remove_padding(self) -> ByteVec
+    pub fn remove_padding(self) -> Bytes
     {
         if self.block_size > 32 { panic!("PCKS#7 does not support with blocks greater than 32 bytes"); }
 
@@ -45,14 +45,14 @@ impl BlockIterator
         match end 
         {
             None => panic!("Invalid padding."),
-            Some(e) => self.values[..e].to_vec()
+            Some(e) => Bytes::from(&self.values[..e])
         }
     }
 }
 
 impl iter::Iterator for BlockIterator
 {
-    type Item = ByteVec;
+    type Item = Bytes;
 
     fn next(&mut self) -> Option<Self::Item>
     { 
@@ -66,7 +66,7 @@ impl iter::Iterator for BlockIterator
 
         self.index = end;
 
-        Some(self.values[start..end].to_vec())
+        Some(Bytes::from(&self.values[start..end]))
     }
 }
 
@@ -87,17 +87,17 @@ mod tests
         let mut iter = BlockIterator::new(&bytes, 8);
 
         assert_eq!(
-            Some(vec![0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7]),
+            Some(Bytes::from(vec![0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7])),
             iter.next()
         );
 
         assert_eq!(
-            Some(vec![0x8, 0x9, 0xA, 0xB, 0xC, 0xD, 0xE, 0xF]),
+            Some(Bytes::from(vec![0x8, 0x9, 0xA, 0xB, 0xC, 0xD, 0xE, 0xF])),
             iter.next()
         );
 
         assert_eq!(
-            Some(vec![0x0]),
+            Some(Bytes::from(vec![0x0])),
             iter.next()
         );
 
@@ -115,12 +115,12 @@ mod tests
         let mut iter = BlockIterator::new(&bytes, 4).with_padding();
 
         assert_eq!(
-            Some(vec![0x0, 0x1, 0x2, 0x3]),
+            Some(Bytes::from(vec![0x0, 0x1, 0x2, 0x3])),
             iter.next()
         );
 
         assert_eq!(
-            Some(vec![0x4, 0x3, 0x3, 0x3]),
+            Some(Bytes::from(vec![0x4, 0x3, 0x3, 0x3])),
             iter.next()
         );
 
@@ -141,7 +141,7 @@ mod tests
         iter.next();
 
         assert_eq!(
-            Some(vec![0x4, 0x4, 0x4, 0x4]),
+            Some(Bytes::from(vec![0x4, 0x4, 0x4, 0x4])),
             iter.next()
         );
     }
@@ -0,0 +1,16 @@
+#![allow(clippy::needless_return)]
+#![allow(clippy::redundant_field_names)]
+#![allow(clippy::upper_case_acronyms)]
+#![allow(clippy::needless_range_loop)]
+
+pub mod crypto;
+pub mod num;
+pub mod types;
+pub mod traits;
+pub mod math;
+pub mod iterators;
+pub mod io;
+pub mod util;
+pub mod stats;
+
+pub mod attacks;
@@ -1,6 +0,0 @@
-use crate::lib::types::ByteVec;
-
-pub trait KeyExpansion
-{
-    fn expand(&self) -> ByteVec;
-}
\ No newline at end of file
@@ -1,2 +0,0 @@
-pub mod xor;
-pub mod aes;
\ No newline at end of file
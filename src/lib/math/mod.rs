@@ -1,2 +0,0 @@
-pub mod gf2_8;
-pub mod byte;
\ No newline at end of file
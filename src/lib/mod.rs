@@ -1,8 +0,0 @@
-pub mod string;
-pub mod crypto;
-pub mod num;
-pub mod types;
-pub mod traits;
-pub mod math;
-pub mod iterators;
-pub mod util;
\ No newline at end of file
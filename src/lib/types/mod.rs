@@ -1,7 +0,0 @@
-mod byte;
-mod byte_vec;
-mod matrix;
-
-pub use byte::Byte;
-pub use byte_vec::ByteVec;
-pub use matrix::Matrix;
\ No newline at end of file
@@ -1,20 +1,67 @@
 #![allow(unused_variables)]
 #![allow(dead_code)]
+#![allow(clippy::needless_return)]
 
 mod challenges;
-mod lib;
-mod attacks;
 
+use std::env;
+use std::process;
+
+use cryptopals::util;
+
+// cryptopals [challenge] [--seed <seed>], the seed can also come from CRYPTOPALS_SEED
 fn main()
 {
-    //challenges::challenge3();
-    //challenges::challenge4();
-    //challenges::challenge6();
-    // challenges::challenge7();
-    // challenges::challenge8();
-    //challenges::challenge10();
-    // challenges::challenge11();
-    // challenges::challenge12();
-    challenges::challenge13();
-}
+    let mut challenge = String::from("13");
+    let mut seed      = env::var(util::SEED_VARIABLE).ok();
+    let mut args      = env::args().skip(1);
+
+    while let Some(arg) = args.next()
+    {
+        match arg.as_str()
+        {
+            "--seed" => match args.next()
+            {
+                Some(value) => seed = Some(value),
+                None        =>
+                {
+                    eprintln!("Missing seed after --seed");
+                    process::exit(1);
+                }
+            },
+            _        => challenge = arg
+        }
+    }
 
+    let seed = match seed.as_deref().map(util::parse_seed)
+    {
+        Some(None) =>
+        {
+            eprintln!("Malformed seed: {}", seed.unwrap());
+            process::exit(1);
+        },
+        Some(seed) => seed,
+        None       => None
+    };
+
+    let (mut rng, seed) = util::seeded_rng(seed);
+    println!("Seed: {}", seed);
+
+    match challenge.as_str()
+    {
+        "3"  => challenges::challenge3(),
+        "4"  => challenges::challenge4(),
+        "6"  => challenges::challenge6(),
+        "7"  => challenges::challenge7(),
+        "8"  => challenges::challenge8(),
+        "10" => challenges::challenge10(),
+        "11" => challenges::challenge11(&mut rng),
+        "12" => challenges::challenge12(&mut rng),
+        "13" => challenges::challenge13(&mut rng),
+        _ =>
+        {
+            eprintln!("Unknown challenge: {}", challenge);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use rand::Rng;
+
+use super::GF256;
+
+// Finite field of order CHARACTERISTIC^DEGREE
+pub trait Field: Copy + PartialEq + fmt::Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const CHARACTERISTIC: u64;
+    const DEGREE: u32;
+
+    fn inv(self) -> Option<Self>;
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    fn is_zero(self) -> bool
+    {
+        self == Self::ZERO
+    }
+
+    fn pow(self, n: u64) -> Self
+    {
+        let mut result = Self::ONE;
+
+        for i in (0..64 - n.leading_zeros()).rev()
+        {
+            result = result * result;
+            if (n >> i) & 1 == 1 { result = result * self; }
+        }
+
+        return result;
+    }
+
+    // n * self, with n taken modulo the characteristic
+    fn mul_int(self, n: u64) -> Self
+    {
+        let mut result = Self::ZERO;
+
+        for i in (0..64 - (n % Self::CHARACTERISTIC).leading_zeros()).rev()
+        {
+            result = result + result;
+            if ((n % Self::CHARACTERISTIC) >> i) & 1 == 1 { result = result + self; }
+        }
+
+        return result;
+    }
+
+    // x -> x^p
+    fn frobenius(self) -> Self
+    {
+        self.pow(Self::CHARACTERISTIC)
+    }
+
+    // Inverse of the frobenius, x^(q/p)
+    fn pth_root(self) -> Self
+    {
+        let mut x = self;
+
+        for _ in 1..Self::DEGREE
+        {
+            x = x.frobenius();
+        }
+
+        return x;
+    }
+}
+
+impl<const POLY: u16> Field for GF256<POLY>
+{
+    const ZERO: Self = GF256::new(0);
+    const ONE: Self = GF256::new(1);
+    const CHARACTERISTIC: u64 = 2;
+    const DEGREE: u32 = 8;
+
+    fn inv(self) -> Option<Self>
+    {
+        GF256::inv(self)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self
+    {
+        GF256::new(rng.gen())
+    }
+}
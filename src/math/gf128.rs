@@ -0,0 +1,277 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+
+use rand::Rng;
+
+use super::Field;
+
+// Element of GF(2^128) = GF(2)[x] / (x^128 + x^7 + x^2 + x + 1)
+// Stored with bit i as the coefficient of x^i, blocks use GCM's reflected order
+// (the most significant bit of the first byte is x^0)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GF128(u128);
+
+impl GF128
+{
+    pub const ZERO: GF128 = GF128(0);
+    pub const ONE: GF128 = GF128(1);
+
+    // Bit i is the coefficient of x^i
+    pub const fn from_poly(value: u128) -> GF128
+    {
+        GF128(value)
+    }
+
+    pub const fn poly(self) -> u128
+    {
+        self.0
+    }
+
+    pub fn from_block(block: &[u8; 16]) -> GF128
+    {
+        GF128(u128::from_be_bytes(*block).reverse_bits())
+    }
+
+    pub fn to_block(self) -> [u8; 16]
+    {
+        self.0.reverse_bits().to_be_bytes()
+    }
+
+    // Panics if the slice isn't 16 bytes long
+    pub fn from_bytes(bytes: &[u8]) -> GF128
+    {
+        match <&[u8; 16]>::try_from(bytes)
+        {
+            Ok(block) => GF128::from_block(block),
+            Err(_)    => panic!("Malformed block ({} != 16)", bytes.len())
+        }
+    }
+
+    pub fn is_zero(self) -> bool
+    {
+        self.0 == 0
+    }
+
+    pub fn pow(self, n: u128) -> GF128
+    {
+        let mut result = GF128::ONE;
+
+        for i in (0..128 - n.leading_zeros()).rev()
+        {
+            result = result * result;
+            if (n >> i) & 1 == 1 { result *= self; }
+        }
+
+        return result;
+    }
+
+    // a^(2^128 - 2)
+    pub fn inv(self) -> Option<GF128>
+    {
+        if self.is_zero() { None } else { Some(self.pow(u128::MAX - 1)) }
+    }
+}
+
+// Carry-less product as (high, low) halves
+// Branch-free on both operands, GHASH multiplies by its secret key H
+fn clmul(a: u128, b: u128) -> (u128, u128)
+{
+    let mut high = 0;
+    let mut low = a & 0u128.wrapping_sub(b & 1);
+
+    for i in 1..128
+    {
+        let mask = 0u128.wrapping_sub((b >> i) & 1);
+
+        low  ^= (a << i) & mask;
+        high ^= (a >> (128 - i)) & mask;
+    }
+
+    return (high, low);
+}
+
+// high * x^128 + low mod x^128 + x^7 + x^2 + x + 1
+fn reduce(high: u128, low: u128) -> u128
+{
+    // x^128 = x^7 + x^2 + x + 1, what overflows again is below 2^7
+    let folded = high ^ high << 1 ^ high << 2 ^ high << 7;
+    let carry = high >> 127 ^ high >> 126 ^ high >> 121;
+
+    return low ^ folded ^ carry ^ carry << 1 ^ carry << 2 ^ carry << 7;
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Add for GF128
+{
+    type Output = GF128;
+
+    fn add(self, rhs: GF128) -> GF128
+    {
+        GF128(self.0 ^ rhs.0)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub for GF128
+{
+    type Output = GF128;
+
+    fn sub(self, rhs: GF128) -> GF128
+    {
+        GF128(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for GF128
+{
+    type Output = GF128;
+
+    fn neg(self) -> GF128
+    {
+        self
+    }
+}
+
+impl Mul for GF128
+{
+    type Output = GF128;
+
+    fn mul(self, rhs: GF128) -> GF128
+    {
+        let (high, low) = clmul(self.0, rhs.0);
+
+        GF128(reduce(high, low))
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div for GF128
+{
+    type Output = GF128;
+
+    fn div(self, rhs: GF128) -> GF128
+    {
+        match rhs.inv()
+        {
+            Some(inverse) => self * inverse,
+            None          => panic!("Division by zero")
+        }
+    }
+}
+
+macro_rules! impl_assign
+{
+    ($tr:ident, $method:ident, $op:tt) =>
+    {
+        impl $tr for GF128
+        {
+            fn $method(&mut self, rhs: GF128)
+            {
+                *self = *self $op rhs;
+            }
+        }
+    }
+}
+
+impl_assign!(AddAssign, add_assign, +);
+impl_assign!(SubAssign, sub_assign, -);
+impl_assign!(MulAssign, mul_assign, *);
+impl_assign!(DivAssign, div_assign, /);
+
+impl Field for GF128
+{
+    const ZERO: GF128 = GF128(0);
+    const ONE: GF128 = GF128(1);
+    const CHARACTERISTIC: u64 = 2;
+    const DEGREE: u32 = 128;
+
+    fn inv(self) -> Option<GF128>
+    {
+        GF128::inv(self)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> GF128
+    {
+        GF128(rng.gen())
+    }
+
+    fn frobenius(self) -> GF128
+    {
+        self * self
+    }
+}
+
+// Hex of the block, as GCM prints it
+impl fmt::Display for GF128
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{:032x}", self.0.reverse_bits())
+    }
+}
+
+impl fmt::Debug for GF128
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "GF128({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::GF128;
+    use crate::math::Field;
+
+    fn block(hex: &str) -> GF128
+    {
+        GF128::from_block(&u128::from_str_radix(hex, 16).unwrap().to_be_bytes())
+    }
+
+    #[test]
+    fn block_order()
+    {
+        assert_eq!(GF128::ONE, block("80000000000000000000000000000000"));
+        assert_eq!("80000000000000000000000000000000", GF128::ONE.to_string());
+        assert_eq!(GF128::from_poly(2), block("40000000000000000000000000000000"));
+    }
+
+    #[test]
+    fn mul()
+    {
+        // GHASH of test case 2 from the GCM specification
+        let h = block("66e94bd4ef8a2c3b884cfa59ca342b2e");
+        let c = block("0388dace60b6a392f328c2b971b2fe78");
+        let l = block("00000000000000000000000000000080");
+
+        let x1 = c * h;
+
+        assert_eq!(block("5e2ec746917062882c85b0685353deb7"), x1);
+        assert_eq!(block("f38cbb1ad69223dcc3457ae5b6b0f885"), (x1 + l) * h);
+
+        // x^127 * x = x^7 + x^2 + x + 1
+        assert_eq!(GF128::from_poly(0x87), GF128::from_poly(1 << 127) * GF128::from_poly(2));
+    }
+
+    #[test]
+    fn inv()
+    {
+        let a = block("66e94bd4ef8a2c3b884cfa59ca342b2e");
+
+        assert_eq!(None, GF128::ZERO.inv());
+        assert_eq!(GF128::ONE, a * a.inv().unwrap());
+        assert_eq!(a, (a * a) / a);
+    }
+
+    #[test]
+    fn pow()
+    {
+        let a = block("0388dace60b6a392f328c2b971b2fe78");
+
+        assert_eq!(a * a * a, a.pow(3));
+        assert_eq!(GF128::ONE, a.pow(u128::MAX));
+        assert_eq!(a, a.pth_root().frobenius());
+    }
+}
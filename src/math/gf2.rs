@@ -0,0 +1,129 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use rand::Rng;
+
+use super::Field;
+
+// Element of GF(2), addition is XOR and multiplication is AND
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GF2(bool);
+
+impl GF2
+{
+    pub const fn new(bit: bool) -> GF2
+    {
+        GF2(bit)
+    }
+
+    pub const fn value(self) -> bool
+    {
+        self.0
+    }
+}
+
+impl From<bool> for GF2
+{
+    fn from(bit: bool) -> GF2
+    {
+        GF2(bit)
+    }
+}
+
+// Only the lowest bit is kept
+impl From<u8> for GF2
+{
+    fn from(value: u8) -> GF2
+    {
+        GF2(value & 1 == 1)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Add for GF2
+{
+    type Output = GF2;
+
+    fn add(self, rhs: GF2) -> GF2
+    {
+        GF2(self.0 ^ rhs.0)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub for GF2
+{
+    type Output = GF2;
+
+    fn sub(self, rhs: GF2) -> GF2
+    {
+        GF2(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for GF2
+{
+    type Output = GF2;
+
+    fn neg(self) -> GF2
+    {
+        self
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Mul for GF2
+{
+    type Output = GF2;
+
+    fn mul(self, rhs: GF2) -> GF2
+    {
+        GF2(self.0 & rhs.0)
+    }
+}
+
+impl Div for GF2
+{
+    type Output = GF2;
+
+    fn div(self, rhs: GF2) -> GF2
+    {
+        if !rhs.0 { panic!("Division by zero"); }
+
+        self
+    }
+}
+
+impl Field for GF2
+{
+    const ZERO: GF2 = GF2(false);
+    const ONE: GF2 = GF2(true);
+    const CHARACTERISTIC: u64 = 2;
+    const DEGREE: u32 = 1;
+
+    fn inv(self) -> Option<GF2>
+    {
+        if self.0 { Some(self) } else { None }
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> GF2
+    {
+        GF2(rng.gen())
+    }
+}
+
+impl fmt::Display for GF2
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{}", self.0 as u8)
+    }
+}
+
+impl fmt::Debug for GF2
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{}", self.0 as u8)
+    }
+}
@@ -0,0 +1,351 @@
+use std::fmt;
+use std::iter::{Sum, Product};
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+
+// x^8 + x^4 + x^3 + x + 1
+pub const AES_POLYNOMIAL: u16 = 0x11B;
+
+pub type AesField = GF256<AES_POLYNOMIAL>;
+
+// Element of GF(2^8) = GF(2)[x] / POLY, using log/antilog tables
+// (lookups depend on the values, so this is not constant-time)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GF256<const POLY: u16>(u8);
+
+struct Tables
+{
+    // exp is doubled so log(a) + log(b) never needs a reduction
+    exp:       [u8; 512],
+    log:       [u8; 256],
+    generator: u8
+}
+
+const fn mul_slow(a: u8, b: u8, poly: u16) -> u8
+{
+    let mut a = a as u16;
+    let mut b = b;
+    let mut result = 0u16;
+
+    while b != 0
+    {
+        if b & 1 == 1 { result ^= a; }
+
+        a <<= 1;
+        if a & 0x100 != 0 { a ^= poly; }
+
+        b >>= 1;
+    }
+
+    result as u8
+}
+
+// Smallest element of multiplicative order 255, there is one iff POLY is irreducible
+const fn find_generator(poly: u16) -> u8
+{
+    let mut g = 2;
+
+    while g < 256
+    {
+        let mut x = g as u8;
+        let mut order = 1;
+
+        while x != 1 && order < 256
+        {
+            x = mul_slow(x, g as u8, poly);
+            order += 1;
+        }
+
+        if x == 1 && order == 255 { return g as u8; }
+
+        g += 1;
+    }
+
+    panic!("Polynomial must be irreducible")
+}
+
+const fn tables(poly: u16) -> Tables
+{
+    if poly < 0x100 || poly > 0x1FF { panic!("Polynomial must be of degree 8"); }
+
+    let generator = find_generator(poly);
+    let mut exp = [0u8; 512];
+    let mut log = [0u8; 256];
+    let mut x = 1u8;
+    let mut i = 0;
+
+    while i < 255
+    {
+        exp[i] = x;
+        exp[i + 255] = x;
+        log[x as usize] = i as u8;
+
+        x = mul_slow(x, generator, poly);
+        i += 1;
+    }
+
+    Tables { exp: exp, log: log, generator: generator }
+}
+
+impl<const POLY: u16> GF256<POLY>
+{
+    // Evaluated once per polynomial, at compile time
+    const TABLES: &'static Tables = &tables(POLY);
+
+    pub const POLYNOMIAL: u16 = POLY;
+    pub const ZERO: Self = GF256(0);
+    pub const ONE: Self = GF256(1);
+
+    pub const fn new(value: u8) -> Self
+    {
+        GF256(value)
+    }
+
+    pub const fn value(self) -> u8
+    {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool
+    {
+        self.0 == 0
+    }
+
+    // Generator of the multiplicative group
+    pub fn generator() -> Self
+    {
+        GF256(Self::TABLES.generator)
+    }
+
+    // generator^n
+    pub fn exp(n: usize) -> Self
+    {
+        GF256(Self::TABLES.exp[n % 255])
+    }
+
+    // Discrete log in base generator (None for zero)
+    pub fn log(self) -> Option<u8>
+    {
+        if self.is_zero() { None } else { Some(Self::TABLES.log[self.0 as usize]) }
+    }
+
+    pub fn inv(self) -> Option<Self>
+    {
+        let log = self.log()? as usize;
+
+        return Some(GF256(Self::TABLES.exp[255 - log]));
+    }
+
+    pub fn pow(self, n: u32) -> Self
+    {
+        match self.log()
+        {
+            Some(log) => Self::exp((log as u64 * n as u64 % 255) as usize),
+            None if n == 0 => Self::ONE,
+            None => Self::ZERO
+        }
+    }
+}
+
+impl<const POLY: u16> From<u8> for GF256<POLY>
+{
+    fn from(value: u8) -> Self
+    {
+        GF256(value)
+    }
+}
+
+impl<const POLY: u16> From<GF256<POLY>> for u8
+{
+    fn from(value: GF256<POLY>) -> u8
+    {
+        value.0
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u16> Add for GF256<POLY>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        GF256(self.0 ^ rhs.0)
+    }
+}
+
+// Characteristic 2, subtraction is addition
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u16> Sub for GF256<POLY>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        GF256(self.0 ^ rhs.0)
+    }
+}
+
+impl<const POLY: u16> Neg for GF256<POLY>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        self
+    }
+}
+
+impl<const POLY: u16> Mul for GF256<POLY>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        if self.is_zero() || rhs.is_zero() { return Self::ZERO; }
+
+        let tables = Self::TABLES;
+
+        return GF256(tables.exp[tables.log[self.0 as usize] as usize + tables.log[rhs.0 as usize] as usize]);
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u16> Div for GF256<POLY>
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self
+    {
+        match rhs.inv()
+        {
+            Some(inverse) => self * inverse,
+            None          => panic!("Division by zero")
+        }
+    }
+}
+
+macro_rules! impl_assign
+{
+    ($tr:ident, $method:ident, $op:tt) =>
+    {
+        impl<const POLY: u16> $tr for GF256<POLY>
+        {
+            fn $method(&mut self, rhs: Self)
+            {
+                *self = *self $op rhs;
+            }
+        }
+    }
+}
+
+impl_assign!(AddAssign, add_assign, +);
+impl_assign!(SubAssign, sub_assign, -);
+impl_assign!(MulAssign, mul_assign, *);
+impl_assign!(DivAssign, div_assign, /);
+
+impl<const POLY: u16> Sum for GF256<POLY>
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self
+    {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<const POLY: u16> Product for GF256<POLY>
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self
+    {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<const POLY: u16> fmt::Display for GF256<POLY>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{:02x}", self.0)
+    }
+}
+
+impl<const POLY: u16> fmt::Debug for GF256<POLY>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "GF256({:#04x})", self.0)
+    }
+}
+
+impl<const POLY: u16> fmt::LowerHex for GF256<POLY>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{AesField, GF256, mul_slow, AES_POLYNOMIAL};
+
+    // x^8 + x^4 + x^3 + x^2 + 1, the usual Reed-Solomon field
+    type RsField = GF256<0x11D>;
+
+    #[test]
+    fn mul()
+    {
+        let a = AesField::new(0x57);
+
+        assert_eq!(0x57, (a * AesField::new(0x01)).value());
+        assert_eq!(0xae, (a * AesField::new(0x02)).value());
+        assert_eq!(0x47, (a * AesField::new(0x04)).value());
+        assert_eq!(0x8e, (a * AesField::new(0x08)).value());
+        assert_eq!(0x07, (a * AesField::new(0x10)).value());
+        assert_eq!(0xfe, (a * AesField::new(0x13)).value());
+        assert_eq!(AesField::ZERO, a * AesField::ZERO);
+
+        for a in 0..=255
+        {
+            for b in 0..=255
+            {
+                assert_eq!(mul_slow(a, b, AES_POLYNOMIAL), (AesField::new(a) * AesField::new(b)).value());
+            }
+        }
+    }
+
+    #[test]
+    fn inv()
+    {
+        assert_eq!(None, AesField::ZERO.inv());
+        assert_eq!(Some(AesField::new(0xca)), AesField::new(0x53).inv());
+
+        for a in 1..=255
+        {
+            let a = RsField::new(a);
+            assert_eq!(RsField::ONE, a * a.inv().unwrap());
+            assert_eq!(RsField::ONE, a / a);
+        }
+    }
+
+    #[test]
+    fn pow()
+    {
+        assert_eq!(3, AesField::generator().value());
+        assert_eq!(2, RsField::generator().value());
+        assert_eq!(AesField::ONE, AesField::new(0x57).pow(255));
+        assert_eq!(AesField::new(0x57) * AesField::new(0x57), AesField::new(0x57).pow(2));
+        assert_eq!(AesField::ONE, AesField::ZERO.pow(0));
+        assert_eq!(RsField::new(0x1d), RsField::exp(8));
+    }
+
+    #[test]
+    fn ops()
+    {
+        let values = [0x01, 0x02, 0x03].iter().map( |v| AesField::new(*v) );
+
+        assert_eq!(AesField::new(0x00), values.clone().sum());
+        assert_eq!(AesField::new(0x06), values.product());
+        assert_eq!(AesField::new(0x57), AesField::new(0x57) - AesField::new(0x13) + AesField::new(0x13));
+        assert_eq!("57", AesField::new(0x57).to_string());
+    }
+}
@@ -0,0 +1,13 @@
+mod field;
+mod gf2;
+mod gf256;
+mod gf128;
+mod modp;
+mod polynomial;
+
+pub use field::Field;
+pub use gf2::GF2;
+pub use gf256::{GF256, AesField, AES_POLYNOMIAL};
+pub use gf128::GF128;
+pub use modp::ModP;
+pub use polynomial::Polynomial;
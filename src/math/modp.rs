@@ -0,0 +1,189 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+
+use rand::Rng;
+
+use super::Field;
+use crate::num;
+
+// Integer modulo the prime P (P must be prime and below 2^63)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModP<const P: u64>(u64);
+
+impl<const P: u64> ModP<P>
+{
+    pub const MODULUS: u64 = P;
+
+    pub const fn new(value: u64) -> Self
+    {
+        ModP(value % P)
+    }
+
+    pub const fn value(self) -> u64
+    {
+        self.0
+    }
+
+    // Integers of either sign
+    pub fn from_i64(value: i64) -> Self
+    {
+        ModP(value.rem_euclid(P as i64) as u64)
+    }
+}
+
+impl<const P: u64> From<u64> for ModP<P>
+{
+    fn from(value: u64) -> Self
+    {
+        ModP::new(value)
+    }
+}
+
+impl<const P: u64> Add for ModP<P>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        ModP((self.0 + rhs.0) % P)
+    }
+}
+
+impl<const P: u64> Sub for ModP<P>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        ModP((self.0 + P - rhs.0) % P)
+    }
+}
+
+impl<const P: u64> Neg for ModP<P>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        ModP((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> Mul for ModP<P>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        ModP((self.0 as u128 * rhs.0 as u128 % P as u128) as u64)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64> Div for ModP<P>
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self
+    {
+        match rhs.inv()
+        {
+            Some(inverse) => self * inverse,
+            None          => panic!("Division by zero")
+        }
+    }
+}
+
+macro_rules! impl_assign
+{
+    ($tr:ident, $method:ident, $op:tt) =>
+    {
+        impl<const P: u64> $tr for ModP<P>
+        {
+            fn $method(&mut self, rhs: Self)
+            {
+                *self = *self $op rhs;
+            }
+        }
+    }
+}
+
+impl_assign!(AddAssign, add_assign, +);
+impl_assign!(SubAssign, sub_assign, -);
+impl_assign!(MulAssign, mul_assign, *);
+impl_assign!(DivAssign, div_assign, /);
+
+impl<const P: u64> Field for ModP<P>
+{
+    const ZERO: Self = ModP(0);
+    const ONE: Self = ModP(1 % P);
+    const CHARACTERISTIC: u64 = P;
+    const DEGREE: u32 = 1;
+
+    fn inv(self) -> Option<Self>
+    {
+        num::invmod(&self.0, &P).map(ModP)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self
+    {
+        ModP(rng.gen_range(0, P))
+    }
+}
+
+impl<const P: u64> fmt::Display for ModP<P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> fmt::Debug for ModP<P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::ModP;
+    use crate::math::{Field, Polynomial};
+
+    type F = ModP<10007>;
+
+    #[test]
+    fn arithmetic()
+    {
+        assert_eq!(F::new(3), F::new(10005) + F::new(5));
+        assert_eq!(F::new(10004), F::new(2) - F::new(5));
+        assert_eq!(F::from_i64(-3), -F::new(3));
+        assert_eq!(F::ONE, F::new(1234) * F::new(1234).inv().unwrap());
+        assert_eq!(None, F::ZERO.inv());
+        assert_eq!(F::new(2), F::new(2).pow(10006) * F::new(2));
+    }
+
+    #[test]
+    fn polynomial_roots()
+    {
+        let mut rng = rand::thread_rng();
+        let expected = [F::new(1), F::new(42), F::new(10006)];
+
+        // Odd characteristic takes the (x + a)^((q - 1) / 2) splitting path
+        let f = &Polynomial::from_roots(&expected) * &Polynomial::new(vec![F::new(1), F::ZERO, F::new(1)]);
+        let mut roots = f.roots(&mut rng);
+        roots.sort();
+
+        assert_eq!(expected.to_vec(), roots);
+
+        let g = &Polynomial::from_roots(&[F::new(5), F::new(5), F::new(7)]) * &Polynomial::from_roots(&[F::new(7)]);
+
+        assert_eq!(
+            vec![(Polynomial::from_roots(&[F::new(5), F::new(7)]), 2)],
+            g.square_free_factorization()
+        );
+    }
+}
@@ -0,0 +1,509 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+
+use rand::Rng;
+
+use super::Field;
+use crate::num::BigUint;
+
+// Polynomial over a finite field, coefficients from the constant term up
+// (no trailing zeros, the zero polynomial has no coefficient)
+#[derive(Clone, PartialEq)]
+pub struct Polynomial<F: Field>
+{
+    coefficients: Vec<F>
+}
+
+impl<F: Field> Polynomial<F>
+{
+    pub fn new(coefficients: Vec<F>) -> Self
+    {
+        let mut p = Polynomial { coefficients: coefficients };
+
+        while let Some(c) = p.coefficients.last()
+        {
+            if !c.is_zero() { break; }
+            p.coefficients.pop();
+        }
+
+        return p;
+    }
+
+    pub fn zero() -> Self
+    {
+        Polynomial { coefficients: vec![] }
+    }
+
+    pub fn one() -> Self
+    {
+        Self::constant(F::ONE)
+    }
+
+    pub fn constant(c: F) -> Self
+    {
+        Self::new(vec![c])
+    }
+
+    // c * x^degree
+    pub fn monomial(c: F, degree: usize) -> Self
+    {
+        let mut coefficients = vec![F::ZERO; degree + 1];
+        coefficients[degree] = c;
+
+        return Self::new(coefficients);
+    }
+
+    // Product of (x - r) for every root
+    pub fn from_roots(roots: &[F]) -> Self
+    {
+        roots.iter().fold(Self::one(), |acc, r| &acc * &Self::new(vec![-*r, F::ONE]))
+    }
+
+    pub fn coefficients(&self) -> &[F]
+    {
+        &self.coefficients
+    }
+
+    // Coefficient of x^i (zero past the degree)
+    pub fn coefficient(&self, i: usize) -> F
+    {
+        *self.coefficients.get(i).unwrap_or(&F::ZERO)
+    }
+
+    pub fn is_zero(&self) -> bool
+    {
+        self.coefficients.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool
+    {
+        self.coefficients == [F::ONE]
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize>
+    {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> F
+    {
+        *self.coefficients.last().unwrap_or(&F::ZERO)
+    }
+
+    pub fn eval(&self, x: F) -> F
+    {
+        self.coefficients.iter().rev().fold(F::ZERO, |acc, c| acc * x + *c)
+    }
+
+    pub fn scale(&self, c: F) -> Self
+    {
+        Self::new(self.coefficients.iter().map( |a| *a * c ).collect())
+    }
+
+    // Divided by its leading coefficient (zero stays zero)
+    pub fn monic(&self) -> Self
+    {
+        match self.leading_coefficient().inv()
+        {
+            Some(inverse) => self.scale(inverse),
+            None          => Self::zero()
+        }
+    }
+
+    pub fn derivative(&self) -> Self
+    {
+        Self::new(
+            self.coefficients.iter()
+                .enumerate()
+                .skip(1)
+                .map( |(i, c)| c.mul_int(i as u64) )
+                .collect()
+        )
+    }
+
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self)
+    {
+        let d = match divisor.degree()
+        {
+            Some(d) => d,
+            None    => panic!("Division by zero")
+        };
+
+        let inverse = divisor.leading_coefficient().inv().unwrap();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![F::ZERO; remainder.len().saturating_sub(d)];
+
+        for i in (d..remainder.len()).rev()
+        {
+            let q = remainder[i] * inverse;
+            if q.is_zero() { continue; }
+
+            quotient[i - d] = q;
+
+            for j in 0..=d
+            {
+                remainder[i - d + j] = remainder[i - d + j] - q * divisor.coefficients[j];
+            }
+        }
+
+        remainder.truncate(d);
+
+        return (Self::new(quotient), Self::new(remainder));
+    }
+
+    // Monic greatest common divisor
+    pub fn gcd(&self, other: &Self) -> Self
+    {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero()
+        {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+
+        return a.monic();
+    }
+
+    // self^exponent mod modulus
+    pub fn pow_mod(&self, exponent: &BigUint, modulus: &Self) -> Self
+    {
+        let base = self % modulus;
+        let mut result = &Self::one() % modulus;
+
+        for i in (0..exponent.bits()).rev()
+        {
+            result = &(&result * &result) % modulus;
+            if exponent.bit(i) { result = &(&result * &base) % modulus; }
+        }
+
+        return result;
+    }
+
+    // self^p mod modulus
+    fn frobenius_mod(&self, modulus: &Self) -> Self
+    {
+        self.pow_mod(&BigUint::from(F::CHARACTERISTIC), modulus)
+    }
+
+    // p-th root of a polynomial whose derivative is zero
+    fn pth_root(&self) -> Self
+    {
+        let p = F::CHARACTERISTIC as usize;
+
+        Self::new(self.coefficients.iter().step_by(p).map( |c| c.pth_root() ).collect())
+    }
+
+    // Monic square-free factors with their multiplicity, f = lc * prod(f_i^i)
+    pub fn square_free_factorization(&self) -> Vec<(Self, usize)>
+    {
+        if self.degree().is_none_or( |d| d == 0 ) { return vec![]; }
+
+        let f = self.monic();
+        let mut factors = vec![];
+        let mut c = f.gcd(&f.derivative());
+        let mut w = &f / &c;
+        let mut i = 1;
+
+        // Factors whose multiplicity isn't a multiple of p
+        while !w.is_one()
+        {
+            let y = w.gcd(&c);
+            let factor = &w / &y;
+
+            if !factor.is_one() { factors.push((factor, i)); }
+
+            w = y;
+            c = &c / &w;
+            i += 1;
+        }
+
+        // What's left is a p-th power
+        if !c.is_one()
+        {
+            let p = F::CHARACTERISTIC as usize;
+
+            for (factor, multiplicity) in c.pth_root().square_free_factorization()
+            {
+                factors.push((factor, multiplicity * p));
+            }
+        }
+
+        return factors;
+    }
+
+    // Distinct roots in F, using Cantor-Zassenhaus on the product of linear factors
+    pub fn roots<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<F>
+    {
+        if self.is_zero() { panic!("Every element is a root of the zero polynomial"); }
+
+        let f = self.monic();
+        let x = Self::monomial(F::ONE, 1);
+
+        // gcd(f, x^q - x) keeps a single copy of every linear factor
+        let mut xq = &x % &f;
+
+        for _ in 0..F::DEGREE
+        {
+            xq = xq.frobenius_mod(&f);
+        }
+
+        let mut roots = vec![];
+        Self::split(&f.gcd(&(&xq - &x)), rng, &mut roots);
+
+        return roots;
+    }
+
+    // Splits a product of distinct linear factors
+    fn split<R: Rng + ?Sized>(g: &Self, rng: &mut R, roots: &mut Vec<F>)
+    {
+        match g.degree()
+        {
+            None | Some(0) => return,
+            Some(1) =>
+            {
+                roots.push(-g.coefficient(0) / g.coefficient(1));
+                return;
+            },
+            _ => {}
+        }
+
+        loop
+        {
+            let a = F::random(rng);
+            let h = Self::splitting_candidate(a, g);
+            let d = g.gcd(&h);
+
+            if d.degree().is_some_and( |d| d > 0 ) && d.degree() < g.degree()
+            {
+                Self::split(&d, rng, roots);
+                Self::split(&(g / &d), rng, roots);
+
+                return;
+            }
+        }
+    }
+
+    // Polynomial sharing about half of the roots of g
+    fn splitting_candidate(a: F, g: &Self) -> Self
+    {
+        if F::CHARACTERISTIC == 2
+        {
+            // Trace of a*x: sum of (a*x)^(2^i) for i < degree, which is 0 or 1 on every root
+            let mut term = &Self::monomial(a, 1) % g;
+            let mut trace = term.clone();
+
+            for _ in 1..F::DEGREE
+            {
+                term = &(&term * &term) % g;
+                trace = &trace + &term;
+            }
+
+            return trace;
+        }
+
+        // (x + a)^((q - 1) / 2) - 1, which is 0 on the roots r with r + a a square
+        let t = Self::new(vec![a, F::ONE]);
+        let q = BigUint::from(F::CHARACTERISTIC).pow(F::DEGREE);
+        let exponent = (q - 1) >> 1;
+
+        return &t.pow_mod(&exponent, g) - &Self::one();
+    }
+}
+
+fn add<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F>
+{
+    let len = a.coefficients.len().max(b.coefficients.len());
+
+    Polynomial::new((0..len).map( |i| a.coefficient(i) + b.coefficient(i) ).collect())
+}
+
+fn sub<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F>
+{
+    let len = a.coefficients.len().max(b.coefficients.len());
+
+    Polynomial::new((0..len).map( |i| a.coefficient(i) - b.coefficient(i) ).collect())
+}
+
+fn mul<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F>
+{
+    if a.is_zero() || b.is_zero() { return Polynomial::zero(); }
+
+    let mut coefficients = vec![F::ZERO; a.coefficients.len() + b.coefficients.len() - 1];
+
+    for (i, x) in a.coefficients.iter().enumerate()
+    {
+        for (j, y) in b.coefficients.iter().enumerate()
+        {
+            coefficients[i + j] = coefficients[i + j] + *x * *y;
+        }
+    }
+
+    return Polynomial::new(coefficients);
+}
+
+fn div<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F>
+{
+    a.div_rem(b).0
+}
+
+fn rem<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F>
+{
+    a.div_rem(b).1
+}
+
+macro_rules! impl_op
+{
+    ($tr:ident, $method:ident, $f:ident) =>
+    {
+        impl<F: Field> $tr<&Polynomial<F>> for &Polynomial<F>
+        {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: &Polynomial<F>) -> Polynomial<F>
+            {
+                $f(self, rhs)
+            }
+        }
+
+        impl<F: Field> $tr<Polynomial<F>> for Polynomial<F>
+        {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: Polynomial<F>) -> Polynomial<F>
+            {
+                $f(&self, &rhs)
+            }
+        }
+    }
+}
+
+impl_op!(Add, add, add);
+impl_op!(Sub, sub, sub);
+impl_op!(Mul, mul, mul);
+impl_op!(Div, div, div);
+impl_op!(Rem, rem, rem);
+
+impl<F: Field> Neg for &Polynomial<F>
+{
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F>
+    {
+        Polynomial::new(self.coefficients.iter().map( |c| -*c ).collect())
+    }
+}
+
+// Highest degree first, e.g. [1]x^2 + [3]
+impl<F: Field> fmt::Debug for Polynomial<F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        if self.is_zero() { return write!(f, "0"); }
+
+        let terms: Vec<String> = self.coefficients.iter()
+            .enumerate()
+            .rev()
+            .filter( |(_, c)| !c.is_zero() )
+            .map( |(i, c)| match i
+            {
+                0 => format!("[{:?}]", c),
+                1 => format!("[{:?}]x", c),
+                _ => format!("[{:?}]x^{}", c, i)
+            })
+            .collect();
+
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Polynomial;
+    use crate::math::{Field, GF128, AesField};
+
+    type P = Polynomial<AesField>;
+
+    fn poly(coefficients: &[u8]) -> P
+    {
+        P::new(coefficients.iter().map( |c| AesField::new(*c) ).collect())
+    }
+
+    #[test]
+    fn arithmetic()
+    {
+        let a = poly(&[1, 2, 3]);
+        let b = poly(&[5, 0, 1]);
+
+        assert_eq!(Some(2), a.degree());
+        assert_eq!(poly(&[4, 2, 2]), &a + &b);
+        assert_eq!(P::zero(), &a - &a);
+        assert_eq!(poly(&[0x01, 0x02]), poly(&[0x01, 0x02, 0x00]));
+        assert_eq!(a.eval(AesField::new(0x57)), AesField::new(1) + AesField::new(2) * AesField::new(0x57) + AesField::new(3) * AesField::new(0x57).pow(2));
+        assert_eq!(poly(&[2, 0]), a.derivative());
+    }
+
+    #[test]
+    fn div_rem()
+    {
+        let a = poly(&[0x11, 0x22, 0x33, 0x44, 0x55]);
+        let b = poly(&[0x66, 0x77, 0x88]);
+        let (q, r) = a.div_rem(&b);
+
+        assert!(r.degree() < b.degree());
+        assert_eq!(a, &(&q * &b) + &r);
+        assert_eq!((P::zero(), b.clone()), b.div_rem(&a));
+    }
+
+    #[test]
+    fn gcd()
+    {
+        let common = poly(&[0x12, 0x34, 0x01]);
+        let a = &common * &poly(&[0x56, 0x01]);
+        let b = &common * &poly(&[0x78, 0x9a, 0xbc]);
+
+        assert_eq!(common, a.gcd(&b));
+        assert!(poly(&[1, 1]).gcd(&poly(&[2, 1])).is_one());
+    }
+
+    #[test]
+    fn square_free_factorization()
+    {
+        let a = poly(&[0x03, 0x01]);
+        let b = poly(&[0x07, 0x05, 0x01]);
+        let c = poly(&[0x09, 0x01]);
+
+        // a * b^2 * c^4 (exercises the p-th root branch)
+        let c2 = &c * &c;
+        let f = (&(&a * &(&b * &b)) * &(&c2 * &c2)).scale(AesField::new(0x42));
+        let factors = f.square_free_factorization();
+
+        assert_eq!(vec![(a, 1), (b, 2), (c, 4)], factors);
+    }
+
+    #[test]
+    fn roots()
+    {
+        let mut rng = rand::thread_rng();
+
+        let expected: Vec<GF128> = (0..6).map( |_| GF128::random(&mut rng) ).collect();
+        let f = Polynomial::from_roots(&expected);
+
+        // Repeated roots are only reported once
+        let f = &f * &Polynomial::from_roots(&expected[..2]);
+
+        let mut roots = f.roots(&mut rng);
+        let mut expected = expected;
+
+        roots.sort();
+        expected.sort();
+
+        assert_eq!(expected, roots);
+        assert_eq!(vec![AesField::ONE], poly(&[1, 0, 1]).roots(&mut rng));
+        assert_eq!(Vec::<AesField>::new(), poly(&[0x42]).roots(&mut rng));
+    }
+}
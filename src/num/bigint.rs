@@ -0,0 +1,415 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg, AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use std::str::FromStr;
+
+use super::{BigUint, ParseNumError};
+
+// Sign and magnitude, zero is never negative
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt
+{
+    negative:  bool,
+    magnitude: BigUint
+}
+
+impl BigInt
+{
+    pub fn new(negative: bool, magnitude: BigUint) -> BigInt
+    {
+        BigInt {
+            negative:  negative && !magnitude.is_zero(),
+            magnitude: magnitude
+        }
+    }
+
+    pub fn zero() -> BigInt
+    {
+        BigInt::from(BigUint::zero())
+    }
+
+    pub fn one() -> BigInt
+    {
+        BigInt::from(BigUint::one())
+    }
+
+    pub fn is_zero(&self) -> bool
+    {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool
+    {
+        self.negative
+    }
+
+    pub fn is_positive(&self) -> bool
+    {
+        !self.negative && !self.is_zero()
+    }
+
+    pub fn magnitude(&self) -> &BigUint
+    {
+        &self.magnitude
+    }
+
+    pub fn abs(&self) -> BigInt
+    {
+        BigInt::from(self.magnitude.clone())
+    }
+
+    // None if negative
+    pub fn to_biguint(&self) -> Option<BigUint>
+    {
+        if self.negative { None } else { Some(self.magnitude.clone()) }
+    }
+
+    pub fn to_i64(&self) -> Option<i64>
+    {
+        let m = self.magnitude.to_u64()?;
+
+        if self.negative
+        {
+            if m > i64::MAX as u64 + 1 { return None; }
+            return Some((m as i64).wrapping_neg());
+        }
+
+        return i64::try_from(m).ok();
+    }
+
+    // Truncated division, the remainder takes the sign of the dividend
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt)
+    {
+        let (q, r) = self.magnitude.div_rem(&divisor.magnitude);
+
+        return (
+            BigInt::new(self.negative != divisor.negative, q),
+            BigInt::new(self.negative, r)
+        );
+    }
+
+    // Least non-negative residue modulo *modulus*
+    pub fn modulo(&self, modulus: &BigUint) -> BigUint
+    {
+        let r = &self.magnitude % modulus;
+
+        if self.negative && !r.is_zero() { modulus - r } else { r }
+    }
+
+    pub fn pow(&self, exponent: u32) -> BigInt
+    {
+        BigInt::new(self.negative && exponent % 2 == 1, self.magnitude.pow(exponent))
+    }
+}
+
+impl From<BigUint> for BigInt
+{
+    fn from(magnitude: BigUint) -> BigInt
+    {
+        BigInt::new(false, magnitude)
+    }
+}
+
+impl From<&BigUint> for BigInt
+{
+    fn from(magnitude: &BigUint) -> BigInt
+    {
+        BigInt::new(false, magnitude.clone())
+    }
+}
+
+impl From<i64> for BigInt
+{
+    fn from(value: i64) -> BigInt
+    {
+        BigInt::new(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl From<u64> for BigInt
+{
+    fn from(value: u64) -> BigInt
+    {
+        BigInt::from(BigUint::from(value))
+    }
+}
+
+impl PartialOrd for BigInt
+{
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt
+{
+    fn cmp(&self, other: &BigInt) -> Ordering
+    {
+        match (self.negative, other.negative)
+        {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true)   => other.magnitude.cmp(&self.magnitude),
+            (false, true)  => Ordering::Greater,
+            (true, false)  => Ordering::Less
+        }
+    }
+}
+
+fn add(a: &BigInt, b: &BigInt) -> BigInt
+{
+    if a.negative == b.negative
+    {
+        return BigInt::new(a.negative, &a.magnitude + &b.magnitude);
+    }
+
+    // Opposite signs, the larger magnitude gives the sign
+    match a.magnitude.cmp(&b.magnitude)
+    {
+        Ordering::Less => BigInt::new(b.negative, &b.magnitude - &a.magnitude),
+        _              => BigInt::new(a.negative, &a.magnitude - &b.magnitude)
+    }
+}
+
+fn sub(a: &BigInt, b: &BigInt) -> BigInt
+{
+    add(a, &-b)
+}
+
+fn mul(a: &BigInt, b: &BigInt) -> BigInt
+{
+    BigInt::new(a.negative != b.negative, &a.magnitude * &b.magnitude)
+}
+
+fn div(a: &BigInt, b: &BigInt) -> BigInt
+{
+    a.div_rem(b).0
+}
+
+fn rem(a: &BigInt, b: &BigInt) -> BigInt
+{
+    a.div_rem(b).1
+}
+
+// Same combinations as BigUint's operators
+macro_rules! impl_op
+{
+    ($tr:ident, $method:ident, $tr_assign:ident, $method_assign:ident, $f:ident) =>
+    {
+        impl $tr<&BigInt> for &BigInt
+        {
+            type Output = BigInt;
+
+            fn $method(self, rhs: &BigInt) -> BigInt
+            {
+                $f(self, rhs)
+            }
+        }
+
+        impl $tr<BigInt> for &BigInt
+        {
+            type Output = BigInt;
+
+            fn $method(self, rhs: BigInt) -> BigInt
+            {
+                $f(self, &rhs)
+            }
+        }
+
+        impl $tr<&BigInt> for BigInt
+        {
+            type Output = BigInt;
+
+            fn $method(self, rhs: &BigInt) -> BigInt
+            {
+                $f(&self, rhs)
+            }
+        }
+
+        impl $tr<BigInt> for BigInt
+        {
+            type Output = BigInt;
+
+            fn $method(self, rhs: BigInt) -> BigInt
+            {
+                $f(&self, &rhs)
+            }
+        }
+
+        impl $tr<i64> for &BigInt
+        {
+            type Output = BigInt;
+
+            fn $method(self, rhs: i64) -> BigInt
+            {
+                $f(self, &BigInt::from(rhs))
+            }
+        }
+
+        impl $tr<i64> for BigInt
+        {
+            type Output = BigInt;
+
+            fn $method(self, rhs: i64) -> BigInt
+            {
+                $f(&self, &BigInt::from(rhs))
+            }
+        }
+
+        impl $tr_assign<&BigInt> for BigInt
+        {
+            fn $method_assign(&mut self, rhs: &BigInt)
+            {
+                *self = $f(self, rhs);
+            }
+        }
+
+        impl $tr_assign<BigInt> for BigInt
+        {
+            fn $method_assign(&mut self, rhs: BigInt)
+            {
+                *self = $f(self, &rhs);
+            }
+        }
+
+        impl $tr_assign<i64> for BigInt
+        {
+            fn $method_assign(&mut self, rhs: i64)
+            {
+                *self = $f(self, &BigInt::from(rhs));
+            }
+        }
+    }
+}
+
+impl_op!(Add, add, AddAssign, add_assign, add);
+impl_op!(Sub, sub, SubAssign, sub_assign, sub);
+impl_op!(Mul, mul, MulAssign, mul_assign, mul);
+impl_op!(Div, div, DivAssign, div_assign, div);
+impl_op!(Rem, rem, RemAssign, rem_assign, rem);
+
+impl Neg for &BigInt
+{
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt
+    {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt
+{
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt
+    {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl FromStr for BigInt
+{
+    type Err = ParseNumError;
+
+    // Optional sign, then anything BigUint accepts
+    fn from_str(string: &str) -> Result<BigInt, ParseNumError>
+    {
+        let (negative, digits) = match string.strip_prefix('-')
+        {
+            Some(rest) => (true, rest),
+            None       => (false, string.strip_prefix('+').unwrap_or(string))
+        };
+
+        let offset = string.len() - digits.len();
+
+        let magnitude = digits.parse::<BigUint>().map_err( |e| match e
+        {
+            ParseNumError::InvalidDigit { position, character } => ParseNumError::InvalidDigit { position: position + offset, character: character },
+            e => e
+        })?;
+
+        return Ok(BigInt::new(negative, magnitude));
+    }
+}
+
+impl fmt::Display for BigInt
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigInt
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for BigInt
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        f.pad_integral(!self.negative, "0x", &self.magnitude.to_str_radix(16))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::BigInt;
+    use crate::num::{BigUint, ParseNumError};
+
+    fn int(s: &str) -> BigInt
+    {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn arithmetic()
+    {
+        assert_eq!(int("-5"), int("3") - int("8"));
+        assert_eq!(int("5"), int("-3") + int("8"));
+        assert_eq!(int("-24"), int("-3") * 8);
+        assert_eq!(BigInt::zero(), int("-7") + 7);
+        assert!(!(int("-7") + 7).is_negative());
+
+        // Truncated like the primitive integers
+        assert_eq!((int("-3"), int("-1")), int("-7").div_rem(&int("2")));
+        assert_eq!((int("-3"), int("1")), int("7").div_rem(&int("-2")));
+    }
+
+    #[test]
+    fn modulo()
+    {
+        assert_eq!(BigUint::from(3u32), int("-7").modulo(&BigUint::from(5u32)));
+        assert_eq!(BigUint::zero(), int("-10").modulo(&BigUint::from(5u32)));
+        assert_eq!(BigUint::from(2u32), int("7").modulo(&BigUint::from(5u32)));
+    }
+
+    #[test]
+    fn ordering()
+    {
+        assert!(int("-10") < int("-2"));
+        assert!(int("-2") < int("0"));
+        assert!(int("0") < int("1"));
+    }
+
+    #[test]
+    fn conversions()
+    {
+        assert_eq!("-340282366920938463463374607431768211456", (-BigInt::from(BigUint::one() << 128)).to_string());
+        assert_eq!("-0xff", format!("{:#x}", int("-255")));
+        assert_eq!(Some(i64::MIN), BigInt::from(i64::MIN).to_i64());
+        assert_eq!(None, (BigInt::from(i64::MIN) - 1).to_i64());
+        assert_eq!(None, int("-1").to_biguint());
+        assert_eq!(int("-4096"), int("-0x1000"));
+
+        assert_eq!(Err(ParseNumError::Empty), "-".parse::<BigInt>());
+        assert_eq!(Err(ParseNumError::InvalidDigit { position: 2, character: 'x' }), "-1x".parse::<BigInt>());
+    }
+}
@@ -0,0 +1,948 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Rem, Shl, Shr, AddAssign, SubAssign, MulAssign, DivAssign, RemAssign, ShlAssign, ShrAssign};
+use std::str::FromStr;
+
+use crate::types::{Bytes, CodecError};
+use crate::traits::{FromHex, ToHex};
+use super::ParseNumError;
+
+// Below this many limbs, schoolbook multiplication beats Karatsuba
+const KARATSUBA_THRESHOLD: usize = 32;
+
+// Arbitrary-precision unsigned integer, stored as little-endian 32-bit limbs
+// without trailing zero limbs (zero is the empty vector)
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint
+{
+    limbs: Vec<u32>
+}
+
+impl BigUint
+{
+    pub fn zero() -> BigUint
+    {
+        BigUint { limbs: vec![] }
+    }
+
+    pub fn one() -> BigUint
+    {
+        BigUint { limbs: vec![1] }
+    }
+
+    fn from_limbs(limbs: Vec<u32>) -> BigUint
+    {
+        let mut n = BigUint { limbs: limbs };
+        n.normalize();
+
+        return n;
+    }
+
+    fn normalize(&mut self)
+    {
+        while let Some(&0) = self.limbs.last()
+        {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn is_zero(&self) -> bool
+    {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool
+    {
+        self.limbs == [1]
+    }
+
+    pub fn is_even(&self) -> bool
+    {
+        self.limbs.first().is_none_or( |l| l & 1 == 0 )
+    }
+
+    pub fn is_odd(&self) -> bool
+    {
+        !self.is_even()
+    }
+
+    // Number of significant bits (0 for zero)
+    pub fn bits(&self) -> usize
+    {
+        match self.limbs.last()
+        {
+            Some(l) => self.limbs.len() * 32 - l.leading_zeros() as usize,
+            None    => 0
+        }
+    }
+
+    // Bit n, counted from the least significant bit
+    pub fn bit(&self, n: usize) -> bool
+    {
+        match self.limbs.get(n / 32)
+        {
+            Some(l) => (l >> (n % 32)) & 1 == 1,
+            None    => false
+        }
+    }
+
+    pub fn set_bit(&mut self, n: usize, value: bool)
+    {
+        if n / 32 >= self.limbs.len()
+        {
+            if !value { return; }
+            self.limbs.resize(n / 32 + 1, 0);
+        }
+
+        if value { self.limbs[n / 32] |= 1 << (n % 32); }
+        else     { self.limbs[n / 32] &= !(1 << (n % 32)); }
+
+        self.normalize();
+    }
+
+    // Number of trailing zero bits (None for zero)
+    pub fn trailing_zeros(&self) -> Option<usize>
+    {
+        let i = self.limbs.iter().position( |l| *l != 0 )?;
+
+        return Some(i * 32 + self.limbs[i].trailing_zeros() as usize);
+    }
+
+    pub fn to_u64(&self) -> Option<u64>
+    {
+        match self.limbs.len()
+        {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u64),
+            2 => Some(self.limbs[0] as u64 | (self.limbs[1] as u64) << 32),
+            _ => None
+        }
+    }
+
+    pub fn from_bytes_be(bytes: &[u8]) -> BigUint
+    {
+        let limbs = bytes.rchunks(4)
+            .map( |c| c.iter().fold(0u32, |acc, b| acc << 8 | *b as u32) )
+            .collect();
+
+        return BigUint::from_limbs(limbs);
+    }
+
+    pub fn from_bytes_le(bytes: &[u8]) -> BigUint
+    {
+        let limbs = bytes.chunks(4)
+            .map( |c| c.iter().rev().fold(0u32, |acc, b| acc << 8 | *b as u32) )
+            .collect();
+
+        return BigUint::from_limbs(limbs);
+    }
+
+    // Minimal big-endian encoding (zero is a single null byte)
+    pub fn to_bytes_be(&self) -> Bytes
+    {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+
+        return bytes;
+    }
+
+    pub fn to_bytes_le(&self) -> Bytes
+    {
+        let mut bytes: Bytes = self.limbs.iter().flat_map( |l| l.to_le_bytes() ).collect();
+        let len = self.bits().div_ceil(8).max(1);
+
+        bytes.resize(len, 0);
+
+        return bytes;
+    }
+
+    // Big-endian encoding left-padded to *length* bytes (panics if it doesn't fit)
+    pub fn to_bytes_be_padded(&self, length: usize) -> Bytes
+    {
+        let bytes = self.to_bytes_be();
+        let len = self.bits().div_ceil(8);
+
+        if len > length { panic!("Number doesn't fit in {} bytes", length); }
+
+        let mut padded = Bytes::from(vec![0; length]);
+        padded[length - len..].copy_from_slice(&bytes[bytes.len() - len..]);
+
+        return padded;
+    }
+
+    pub fn from_str_radix(string: &str, radix: u32) -> Result<BigUint, ParseNumError>
+    {
+        if !(2..=36).contains(&radix) { panic!("Radix must be in 2..=36 (got {})", radix); }
+        if string.is_empty() { return Err(ParseNumError::Empty); }
+
+        let mut n = BigUint::zero();
+
+        for (position, c) in string.chars().enumerate()
+        {
+            let digit = match c.to_digit(radix)
+            {
+                Some(d) => d,
+                None    => return Err(ParseNumError::InvalidDigit { position: position, character: c })
+            };
+
+            n.mul_add_small(radix, digit);
+        }
+
+        return Ok(n);
+    }
+
+    pub fn to_str_radix(&self, radix: u32) -> String
+    {
+        if !(2..=36).contains(&radix) { panic!("Radix must be in 2..=36 (got {})", radix); }
+        if self.is_zero() { return String::from("0"); }
+
+        // Peel off as many digits as fit in a limb at once
+        let mut chunk_digits = 1;
+        let mut chunk = radix;
+
+        while let Some(next) = chunk.checked_mul(radix)
+        {
+            chunk = next;
+            chunk_digits += 1;
+        }
+
+        let mut digits = vec![];
+        let mut n = self.clone();
+
+        while !n.is_zero()
+        {
+            let mut r = n.div_rem_small(chunk);
+
+            for _ in 0..chunk_digits
+            {
+                if n.is_zero() && r == 0 { break; }
+
+                digits.push(std::char::from_digit(r % radix, radix).unwrap());
+                r /= radix;
+            }
+        }
+
+        return digits.iter().rev().collect();
+    }
+
+    // self = self * m + a
+    fn mul_add_small(&mut self, m: u32, a: u32)
+    {
+        let mut carry = a as u64;
+
+        for l in self.limbs.iter_mut()
+        {
+            let t = *l as u64 * m as u64 + carry;
+            *l = t as u32;
+            carry = t >> 32;
+        }
+
+        if carry != 0 { self.limbs.push(carry as u32); }
+        self.normalize();
+    }
+
+    // self = self / d, returns the remainder
+    fn div_rem_small(&mut self, d: u32) -> u32
+    {
+        if d == 0 { panic!("Division by zero"); }
+
+        let mut r = 0u64;
+
+        for l in self.limbs.iter_mut().rev()
+        {
+            let t = r << 32 | *l as u64;
+            *l = (t / d as u64) as u32;
+            r = t % d as u64;
+        }
+
+        self.normalize();
+
+        return r as u32;
+    }
+
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint)
+    {
+        if divisor.is_zero() { panic!("Division by zero"); }
+
+        if self < divisor
+        {
+            return (BigUint::zero(), self.clone());
+        }
+
+        if divisor.limbs.len() == 1
+        {
+            let mut q = self.clone();
+            let r = q.div_rem_small(divisor.limbs[0]);
+
+            return (q, BigUint::from(r));
+        }
+
+        let (q, r) = div_rem_limbs(&self.limbs, &divisor.limbs);
+
+        return (BigUint::from_limbs(q), BigUint::from_limbs(r));
+    }
+
+    pub fn pow(&self, exponent: u32) -> BigUint
+    {
+        let mut result = BigUint::one();
+
+        for i in (0..32 - exponent.leading_zeros()).rev()
+        {
+            result = &result * &result;
+            if (exponent >> i) & 1 == 1 { result = &result * self; }
+        }
+
+        return result;
+    }
+
+    // self^exponent mod modulus, by left-to-right square-and-multiply
+    pub fn modpow(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint
+    {
+        if modulus.is_zero() { panic!("Modulus must not be zero"); }
+
+        let base = self % modulus;
+        let mut result = BigUint::one() % modulus;
+
+        for i in (0..exponent.bits()).rev()
+        {
+            result = &(&result * &result) % modulus;
+            if exponent.bit(i) { result = &(&result * &base) % modulus; }
+        }
+
+        return result;
+    }
+}
+
+// Limb slices may carry trailing zeros in the helpers below
+fn trimmed(a: &[u32]) -> &[u32]
+{
+    let len = a.iter().rposition( |l| *l != 0 ).map_or(0, |i| i + 1);
+
+    return &a[..len];
+}
+
+fn cmp_limbs(a: &[u32], b: &[u32]) -> Ordering
+{
+    let (a, b) = (trimmed(a), trimmed(b));
+
+    a.len().cmp(&b.len()).then_with( || a.iter().rev().cmp(b.iter().rev()) )
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32>
+{
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+
+    for i in 0..a.len()
+    {
+        let t = a[i] as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(t as u32);
+        carry = t >> 32;
+    }
+
+    if carry != 0 { result.push(carry as u32); }
+
+    return result;
+}
+
+// a - b, with a >= b
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32>
+{
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for i in 0..a.len()
+    {
+        let t = a[i] as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        result.push(t as u32);
+        borrow = if t < 0 { 1 } else { 0 };
+    }
+
+    if borrow != 0 || trimmed(b).len() > a.len() { panic!("Subtraction underflow"); }
+
+    return result;
+}
+
+// dst[offset..] += src
+fn add_into(dst: &mut Vec<u32>, src: &[u32], offset: usize)
+{
+    if dst.len() < offset + src.len() { dst.resize(offset + src.len(), 0); }
+
+    let mut carry = 0u64;
+    let mut i = 0;
+
+    while i < src.len() || carry != 0
+    {
+        if offset + i == dst.len() { dst.push(0); }
+
+        let t = dst[offset + i] as u64 + *src.get(i).unwrap_or(&0) as u64 + carry;
+        dst[offset + i] = t as u32;
+        carry = t >> 32;
+        i += 1;
+    }
+}
+
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32>
+{
+    let mut result = vec![0u32; a.len() + b.len()];
+
+    for i in 0..a.len()
+    {
+        let mut carry = 0u64;
+
+        for j in 0..b.len()
+        {
+            let t = a[i] as u64 * b[j] as u64 + result[i + j] as u64 + carry;
+            result[i + j] = t as u32;
+            carry = t >> 32;
+        }
+
+        result[i + b.len()] = carry as u32;
+    }
+
+    return result;
+}
+
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32>
+{
+    let (a, b) = (trimmed(a), trimmed(b));
+
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD
+    {
+        return mul_schoolbook(a, b);
+    }
+
+    // a = a1 * B^m + a0, b = b1 * B^m + b0
+    let m = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+
+    let z0 = mul_limbs(a0, b0);
+    let z2 = mul_limbs(a1, b1);
+    let z1 = mul_limbs(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    let z1 = sub_limbs(trimmed(&sub_limbs(&z1, &z0)), &z2);
+
+    let mut result = z0;
+    add_into(&mut result, trimmed(&z1), m);
+    add_into(&mut result, trimmed(&z2), 2 * m);
+
+    return result;
+}
+
+fn shl_limbs(a: &[u32], shift: usize) -> Vec<u32>
+{
+    let (limbs, bits) = (shift / 32, shift % 32);
+    let mut result = vec![0u32; limbs];
+
+    if bits == 0
+    {
+        result.extend_from_slice(a);
+    }
+    else
+    {
+        let mut carry = 0u32;
+
+        for l in a
+        {
+            result.push(l << bits | carry);
+            carry = l >> (32 - bits);
+        }
+
+        result.push(carry);
+    }
+
+    return result;
+}
+
+fn shr_limbs(a: &[u32], shift: usize) -> Vec<u32>
+{
+    let (limbs, bits) = (shift / 32, shift % 32);
+    if limbs >= a.len() { return vec![]; }
+
+    let a = &a[limbs..];
+
+    if bits == 0 { return a.to_vec(); }
+
+    return (0..a.len())
+        .map( |i| a[i] >> bits | a.get(i + 1).map_or(0, |h| h << (32 - bits)) )
+        .collect();
+}
+
+// Knuth's algorithm D, with a divisor of at least two limbs
+fn div_rem_limbs(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>)
+{
+    const BASE: u64 = 1 << 32;
+
+    let n = v.len();
+    let m = u.len() - n;
+
+    // Normalize so the top bit of the divisor is set
+    let s = v[n - 1].leading_zeros() as usize;
+    let vn = shl_limbs(v, s);
+    let mut un = shl_limbs(u, s);
+    un.resize(u.len() + 1, 0);
+
+    let mut q = vec![0u32; m + 1];
+
+    for j in (0..=m).rev()
+    {
+        let num = (un[j + n] as u64) << 32 | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+
+        while qhat >= BASE || qhat * vn[n - 2] as u64 > (rhat << 32 | un[j + n - 2] as u64)
+        {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= BASE { break; }
+        }
+
+        // un[j..j+n+1] -= qhat * vn
+        let mut k = 0i64;
+
+        for i in 0..n
+        {
+            let p = qhat * vn[i] as u64;
+            let t = un[i + j] as i64 - k - (p & 0xFFFF_FFFF) as i64;
+            un[i + j] = t as u32;
+            k = (p >> 32) as i64 - (t >> 32);
+        }
+
+        let t = un[j + n] as i64 - k;
+        un[j + n] = t as u32;
+        q[j] = qhat as u32;
+
+        // qhat was one too large, add the divisor back
+        if t < 0
+        {
+            q[j] = q[j].wrapping_sub(1);
+            let mut carry = 0u64;
+
+            for i in 0..n
+            {
+                let t = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = t as u32;
+                carry = t >> 32;
+            }
+
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+    }
+
+    return (q, shr_limbs(&un[..n], s));
+}
+
+macro_rules! impl_from_primitive
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl From<$t> for BigUint
+            {
+                fn from(value: $t) -> BigUint
+                {
+                    let value = value as u128;
+
+                    BigUint::from_limbs((0..4).map( |i| (value >> (32 * i)) as u32 ).collect())
+                }
+            }
+        )*
+    }
+}
+
+impl_from_primitive!(u8, u16, u32, u64, u128, usize);
+
+impl PartialOrd for BigUint
+{
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint
+{
+    fn cmp(&self, other: &BigUint) -> Ordering
+    {
+        cmp_limbs(&self.limbs, &other.limbs)
+    }
+}
+
+fn add(a: &BigUint, b: &BigUint) -> BigUint
+{
+    BigUint::from_limbs(add_limbs(&a.limbs, &b.limbs))
+}
+
+fn sub(a: &BigUint, b: &BigUint) -> BigUint
+{
+    BigUint::from_limbs(sub_limbs(&a.limbs, &b.limbs))
+}
+
+fn mul(a: &BigUint, b: &BigUint) -> BigUint
+{
+    if a.is_zero() || b.is_zero() { return BigUint::zero(); }
+
+    BigUint::from_limbs(mul_limbs(&a.limbs, &b.limbs))
+}
+
+fn div(a: &BigUint, b: &BigUint) -> BigUint
+{
+    a.div_rem(b).0
+}
+
+fn rem(a: &BigUint, b: &BigUint) -> BigUint
+{
+    a.div_rem(b).1
+}
+
+// Implements the operator for every owned/borrowed combination, and with primitive rhs
+macro_rules! impl_op
+{
+    ($tr:ident, $method:ident, $tr_assign:ident, $method_assign:ident, $f:ident) =>
+    {
+        impl $tr<&BigUint> for &BigUint
+        {
+            type Output = BigUint;
+
+            fn $method(self, rhs: &BigUint) -> BigUint
+            {
+                $f(self, rhs)
+            }
+        }
+
+        impl $tr<BigUint> for &BigUint
+        {
+            type Output = BigUint;
+
+            fn $method(self, rhs: BigUint) -> BigUint
+            {
+                $f(self, &rhs)
+            }
+        }
+
+        impl $tr<&BigUint> for BigUint
+        {
+            type Output = BigUint;
+
+            fn $method(self, rhs: &BigUint) -> BigUint
+            {
+                $f(&self, rhs)
+            }
+        }
+
+        impl $tr<BigUint> for BigUint
+        {
+            type Output = BigUint;
+
+            fn $method(self, rhs: BigUint) -> BigUint
+            {
+                $f(&self, &rhs)
+            }
+        }
+
+        impl $tr<u64> for &BigUint
+        {
+            type Output = BigUint;
+
+            fn $method(self, rhs: u64) -> BigUint
+            {
+                $f(self, &BigUint::from(rhs))
+            }
+        }
+
+        impl $tr<u64> for BigUint
+        {
+            type Output = BigUint;
+
+            fn $method(self, rhs: u64) -> BigUint
+            {
+                $f(&self, &BigUint::from(rhs))
+            }
+        }
+
+        impl $tr_assign<&BigUint> for BigUint
+        {
+            fn $method_assign(&mut self, rhs: &BigUint)
+            {
+                *self = $f(self, rhs);
+            }
+        }
+
+        impl $tr_assign<BigUint> for BigUint
+        {
+            fn $method_assign(&mut self, rhs: BigUint)
+            {
+                *self = $f(self, &rhs);
+            }
+        }
+
+        impl $tr_assign<u64> for BigUint
+        {
+            fn $method_assign(&mut self, rhs: u64)
+            {
+                *self = $f(self, &BigUint::from(rhs));
+            }
+        }
+    }
+}
+
+impl_op!(Add, add, AddAssign, add_assign, add);
+impl_op!(Sub, sub, SubAssign, sub_assign, sub);
+impl_op!(Mul, mul, MulAssign, mul_assign, mul);
+impl_op!(Div, div, DivAssign, div_assign, div);
+impl_op!(Rem, rem, RemAssign, rem_assign, rem);
+
+impl Shl<usize> for &BigUint
+{
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint
+    {
+        BigUint::from_limbs(shl_limbs(&self.limbs, shift))
+    }
+}
+
+impl Shl<usize> for BigUint
+{
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint
+    {
+        &self << shift
+    }
+}
+
+impl ShlAssign<usize> for BigUint
+{
+    fn shl_assign(&mut self, shift: usize)
+    {
+        *self = &*self << shift;
+    }
+}
+
+impl Shr<usize> for &BigUint
+{
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint
+    {
+        BigUint::from_limbs(shr_limbs(&self.limbs, shift))
+    }
+}
+
+impl Shr<usize> for BigUint
+{
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint
+    {
+        &self >> shift
+    }
+}
+
+impl ShrAssign<usize> for BigUint
+{
+    fn shr_assign(&mut self, shift: usize)
+    {
+        *self = &*self >> shift;
+    }
+}
+
+impl FromStr for BigUint
+{
+    type Err = ParseNumError;
+
+    // Decimal, or hexadecimal with a 0x prefix
+    fn from_str(string: &str) -> Result<BigUint, ParseNumError>
+    {
+        match string.strip_prefix("0x")
+        {
+            Some(hex) => BigUint::from_str_radix(hex, 16).map_err( |e| match e
+            {
+                ParseNumError::InvalidDigit { position, character } => ParseNumError::InvalidDigit { position: position + 2, character: character },
+                e => e
+            }),
+            None => BigUint::from_str_radix(string, 10)
+        }
+    }
+}
+
+impl FromHex for BigUint
+{
+    fn try_from_hex(string: &str) -> Result<BigUint, CodecError>
+    {
+        if string.is_empty() { return Ok(BigUint::zero()); }
+
+        BigUint::from_str_radix(string, 16).map_err( |e| match e
+        {
+            ParseNumError::InvalidDigit { position, character } => CodecError::InvalidChar { position: position, character: character },
+            ParseNumError::Empty => unreachable!()
+        })
+    }
+}
+
+impl ToHex for BigUint
+{
+    fn to_hex(&self) -> String
+    {
+        format!("{:x}", self)
+    }
+}
+
+impl fmt::Display for BigUint
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        f.pad_integral(true, "", &self.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigUint
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for BigUint
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        f.pad_integral(true, "0x", &self.to_str_radix(16))
+    }
+}
+
+impl fmt::UpperHex for BigUint
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        f.pad_integral(true, "0x", &self.to_str_radix(16).to_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::BigUint;
+    use crate::num::ParseNumError;
+    use crate::traits::{FromHex, ToHex};
+
+    fn big(s: &str) -> BigUint
+    {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn add_sub()
+    {
+        let a = big("0xffffffffffffffffffffffff");
+
+        assert_eq!(big("0x1000000000000000000000000"), &a + 1);
+        assert_eq!(a, big("0x1000000000000000000000000") - 1);
+        assert_eq!(BigUint::zero(), &a - &a);
+    }
+
+    #[test]
+    #[should_panic(expected = "Subtraction underflow")]
+    fn sub_underflow()
+    {
+        let _ = BigUint::from(1u32) - BigUint::from(2u32);
+    }
+
+    #[test]
+    fn mul()
+    {
+        assert_eq!(
+            big("121932631137021794322511812221002896"),
+            big("123456789012345678") * big("987654321098765432")
+        );
+
+        // Large enough to go through Karatsuba, checked against schoolbook
+        let a = (big("3") << 2000) - 12345;
+        let b = (big("7") << 1500) + 99;
+        let expected = BigUint::from_limbs(super::mul_schoolbook(&a.limbs, &b.limbs));
+
+        assert_eq!(expected, &a * &b);
+        assert_eq!(&(&a * &b) / &b, a);
+    }
+
+    #[test]
+    fn div_rem()
+    {
+        let (q, r) = big("121932631137021794322511812221002927").div_rem(&big("987654321098765432"));
+
+        assert_eq!(big("123456789012345678"), q);
+        assert_eq!(big("31"), r);
+
+        let a = (big("1") << 521) - 1;
+        let b = big("0xfffffffffffffffffffffffffffffffeffffffffffffffff");
+        let (q, r) = a.div_rem(&b);
+
+        assert!(r < b);
+        assert_eq!(a, q * &b + r);
+    }
+
+    #[test]
+    fn shifts()
+    {
+        assert_eq!(big("0x123456789abcdef00000"), big("0x123456789abcdef") << 20);
+        assert_eq!(big("0x1234"), big("0x123456789abcdef") >> 44);
+        assert_eq!(BigUint::zero(), big("0x1234") >> 100);
+    }
+
+    #[test]
+    fn bits()
+    {
+        let n = big("0x10000000000000004");
+
+        assert_eq!(65, n.bits());
+        assert!(n.bit(64) && n.bit(2) && !n.bit(3));
+        assert_eq!(Some(2), n.trailing_zeros());
+        assert_eq!(None, BigUint::zero().trailing_zeros());
+    }
+
+    #[test]
+    fn conversions()
+    {
+        let two_128 = "340282366920938463463374607431768211456";
+
+        assert_eq!(two_128, (BigUint::one() << 128).to_string());
+        assert_eq!(BigUint::one() << 128, big(two_128));
+        assert_eq!("0", BigUint::zero().to_string());
+        assert_eq!("1a2b", BigUint::from_hex("1A2B").to_hex());
+        assert_eq!("0x1A2B", format!("{:#X}", BigUint::from(0x1a2bu32)));
+        assert_eq!("1010", BigUint::from(10u8).to_str_radix(2));
+        assert_eq!(Some(u64::MAX), BigUint::from(u64::MAX).to_u64());
+        assert_eq!(None, (BigUint::one() << 64).to_u64());
+
+        assert_eq!(Err(ParseNumError::Empty), "".parse::<BigUint>());
+        assert_eq!(Err(ParseNumError::InvalidDigit { position: 3, character: 'g' }), "0x1g".parse::<BigUint>());
+    }
+
+    #[test]
+    fn bytes()
+    {
+        let n = BigUint::from_bytes_be(&[0x01, 0x02, 0x03, 0x04, 0x05]);
+
+        assert_eq!(BigUint::from(0x0102030405u64), n);
+        assert_eq!(&[0x01, 0x02, 0x03, 0x04, 0x05][..], &n.to_bytes_be()[..]);
+        assert_eq!(&[0x05, 0x04, 0x03, 0x02, 0x01][..], &n.to_bytes_le()[..]);
+        assert_eq!(n, BigUint::from_bytes_le(&n.to_bytes_le()));
+        assert_eq!(&[0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05][..], &n.to_bytes_be_padded(7)[..]);
+        assert_eq!(&[0x00][..], &BigUint::zero().to_bytes_be()[..]);
+    }
+
+    #[test]
+    fn modpow()
+    {
+        assert_eq!(BigUint::from(445u32), BigUint::from(4u32).modpow(&BigUint::from(13u32), &BigUint::from(497u32)));
+
+        // Fermat's little theorem on the Mersenne prime 2^127 - 1
+        let p = (BigUint::one() << 127) - 1;
+
+        assert!(BigUint::from(3u32).modpow(&(&p - 1), &p).is_one());
+        assert_eq!(BigUint::from(1024u32), BigUint::from(2u32).pow(10));
+    }
+}
@@ -0,0 +1,24 @@
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseNumError
+{
+    Empty,
+    // Position is the char index in the original input (sign included)
+    InvalidDigit { position: usize, character: char }
+}
+
+impl fmt::Display for ParseNumError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        match self
+        {
+            Self::Empty                                => write!(f, "cannot parse a number from an empty string"),
+            Self::InvalidDigit { position, character } => write!(f, "invalid digit {:?} at position {}", character, position)
+        }
+    }
+}
+
+impl error::Error for ParseNumError {}
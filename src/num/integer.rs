@@ -0,0 +1,190 @@
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Rem, Shl, Shr};
+
+use rand::Rng;
+
+use super::{BigUint, BigInt};
+
+// Unsigned integer the number-theory routines are generic over (u64 or BigUint)
+pub trait Integer: Clone + Ord + fmt::Debug + From<u32>
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + Div<Output = Self> + Rem<Output = Self>
+    + Shl<usize, Output = Self> + Shr<usize, Output = Self>
+{
+    // Wide enough to hold Bezout coefficients
+    type Signed: Clone + fmt::Debug + From<Self> + Sub<Output = Self::Signed> + Mul<Output = Self::Signed>;
+
+    fn zero() -> Self
+    {
+        Self::from(0)
+    }
+
+    fn one() -> Self
+    {
+        Self::from(1)
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        *self == Self::zero()
+    }
+
+    fn is_one(&self) -> bool
+    {
+        *self == Self::one()
+    }
+
+    fn is_even(&self) -> bool;
+
+    // Number of significant bits
+    fn bits(&self) -> usize;
+
+    // Number of trailing zero bits (0 for zero)
+    fn trailing_zeros(&self) -> usize;
+
+    fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self;
+    fn pow_mod(&self, exponent: &Self, modulus: &Self) -> Self;
+
+    // Least non-negative residue of a signed value
+    fn reduce(value: &Self::Signed, modulus: &Self) -> Self;
+
+    // Uniform in [0, 2^bits)
+    fn random_bits<R: Rng + ?Sized>(bits: usize, rng: &mut R) -> Self;
+
+    // Uniform in [0, bound)
+    fn random_below<R: Rng + ?Sized>(bound: &Self, rng: &mut R) -> Self
+    {
+        if bound.is_zero() { panic!("Bound must not be zero"); }
+
+        loop
+        {
+            let candidate = Self::random_bits(bound.bits(), rng);
+            if candidate < *bound { return candidate; }
+        }
+    }
+}
+
+impl Integer for u64
+{
+    type Signed = i128;
+
+    fn is_even(&self) -> bool
+    {
+        self & 1 == 0
+    }
+
+    fn bits(&self) -> usize
+    {
+        (64 - self.leading_zeros()) as usize
+    }
+
+    fn trailing_zeros(&self) -> usize
+    {
+        if *self == 0 { 0 } else { u64::trailing_zeros(*self) as usize }
+    }
+
+    fn mul_mod(&self, rhs: &u64, modulus: &u64) -> u64
+    {
+        (*self as u128 * *rhs as u128 % *modulus as u128) as u64
+    }
+
+    fn pow_mod(&self, exponent: &u64, modulus: &u64) -> u64
+    {
+        let mut result = 1 % modulus;
+        let mut base = self % modulus;
+        let mut exponent = *exponent;
+
+        while exponent != 0
+        {
+            if exponent & 1 == 1 { result = result.mul_mod(&base, modulus); }
+
+            base = base.mul_mod(&base, modulus);
+            exponent >>= 1;
+        }
+
+        return result;
+    }
+
+    fn reduce(value: &i128, modulus: &u64) -> u64
+    {
+        value.rem_euclid(*modulus as i128) as u64
+    }
+
+    fn random_bits<R: Rng + ?Sized>(bits: usize, rng: &mut R) -> u64
+    {
+        if bits == 0 { return 0; }
+        if bits > 64 { panic!("Out of range"); }
+
+        rng.gen::<u64>() >> (64 - bits)
+    }
+}
+
+impl Integer for BigUint
+{
+    type Signed = BigInt;
+
+    fn zero() -> BigUint
+    {
+        BigUint::zero()
+    }
+
+    fn one() -> BigUint
+    {
+        BigUint::one()
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        BigUint::is_zero(self)
+    }
+
+    fn is_one(&self) -> bool
+    {
+        BigUint::is_one(self)
+    }
+
+    fn is_even(&self) -> bool
+    {
+        BigUint::is_even(self)
+    }
+
+    fn bits(&self) -> usize
+    {
+        BigUint::bits(self)
+    }
+
+    fn trailing_zeros(&self) -> usize
+    {
+        BigUint::trailing_zeros(self).unwrap_or(0)
+    }
+
+    fn mul_mod(&self, rhs: &BigUint, modulus: &BigUint) -> BigUint
+    {
+        &(self * rhs) % modulus
+    }
+
+    fn pow_mod(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint
+    {
+        self.modpow(exponent, modulus)
+    }
+
+    fn reduce(value: &BigInt, modulus: &BigUint) -> BigUint
+    {
+        value.modulo(modulus)
+    }
+
+    fn random_bits<R: Rng + ?Sized>(bits: usize, rng: &mut R) -> BigUint
+    {
+        let mut bytes = vec![0u8; bits.div_ceil(8)];
+        rng.fill_bytes(&mut bytes);
+
+        let excess = bytes.len() * 8 - bits;
+
+        if let Some(top) = bytes.first_mut()
+        {
+            *top &= 0xFF >> excess;
+        }
+
+        return BigUint::from_bytes_be(&bytes);
+    }
+}
@@ -0,0 +1,44 @@
+mod error;
+mod biguint;
+mod bigint;
+mod integer;
+mod modular;
+mod root;
+mod prime;
+
+pub use error::ParseNumError;
+pub use biguint::BigUint;
+pub use bigint::BigInt;
+pub use integer::Integer;
+pub use modular::{egcd, invmod, crt, jacobi, legendre, sqrt_mod};
+pub use root::{nth_root, nth_root_exact};
+pub use prime::{miller_rabin, is_prime, is_probable_prime, random_prime, random_safe_prime};
+
+pub fn gcd(a: u32, b: u32) -> u32
+{
+    let mut t: u32;
+    let mut a = a;
+    let mut b = b;
+
+    while b != 0
+    {
+        t = b;
+        b = a % b;
+        a = t;
+    }
+
+    return a;
+}
+
+#[cfg(test)]
+mod tests
+{
+    #[test]
+    fn gcd()
+    {
+        assert_eq!(1, super::gcd(45, 52));
+        assert_eq!(1, super::gcd(52, 45));
+
+        assert_eq!(4, super::gcd(20, 16));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,221 @@
+use std::mem;
+
+use super::Integer;
+
+// Returns (g, x, y) with a*x + b*y = g = gcd(a, b)
+pub fn egcd<T: Integer>(a: &T, b: &T) -> (T, T::Signed, T::Signed)
+{
+    let (mut old_r, mut r) = (a.clone(), b.clone());
+    let (mut old_s, mut s) = (T::Signed::from(T::one()), T::Signed::from(T::zero()));
+    let (mut old_t, mut t) = (T::Signed::from(T::zero()), T::Signed::from(T::one()));
+
+    while !r.is_zero()
+    {
+        let q = old_r.clone() / r.clone();
+        let q_signed = T::Signed::from(q.clone());
+
+        let next_r = old_r - q * r.clone();
+        old_r = mem::replace(&mut r, next_r);
+
+        let next_s = old_s - q_signed.clone() * s.clone();
+        old_s = mem::replace(&mut s, next_s);
+
+        let next_t = old_t - q_signed * t.clone();
+        old_t = mem::replace(&mut t, next_t);
+    }
+
+    return (old_r, old_s, old_t);
+}
+
+// Inverse of a modulo m, None if they aren't coprime
+pub fn invmod<T: Integer>(a: &T, modulus: &T) -> Option<T>
+{
+    let (g, x, _) = egcd(&(a.clone() % modulus.clone()), modulus);
+
+    if !g.is_one() { return None; }
+
+    return Some(T::reduce(&x, modulus));
+}
+
+// Solves x = a_i mod n_i for pairwise coprime moduli, returns (x, product of moduli)
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)>
+{
+    let mut x = T::zero();
+    let mut m = T::one();
+
+    for (a, n) in congruences
+    {
+        // x' = x + m * k with k = (a - x) / m mod n, so x' stays below m * n
+        let inverse = invmod(&(m.clone() % n.clone()), n)?;
+        let diff = (a.clone() % n.clone() + n.clone() - x.clone() % n.clone()) % n.clone();
+        let k = diff.mul_mod(&inverse, n);
+
+        x = x + m.clone() * k;
+        m = m * n.clone();
+    }
+
+    return Some((x, m));
+}
+
+fn small<T: Integer>(value: &T, modulus: u32) -> T
+{
+    value.clone() % T::from(modulus)
+}
+
+// Jacobi symbol (a/n) for an odd positive n
+pub fn jacobi<T: Integer>(a: &T, n: &T) -> i32
+{
+    if n.is_even() || n.is_zero() { panic!("Jacobi symbol needs an odd positive modulus"); }
+
+    let mut a = a.clone() % n.clone();
+    let mut n = n.clone();
+    let mut result = 1;
+
+    while !a.is_zero()
+    {
+        // (2/n) = -1 when n = 3, 5 mod 8
+        while a.is_even()
+        {
+            a = a >> 1;
+
+            let r = small(&n, 8);
+            if r == T::from(3) || r == T::from(5) { result = -result; }
+        }
+
+        // Quadratic reciprocity
+        mem::swap(&mut a, &mut n);
+        if small(&a, 4) == T::from(3) && small(&n, 4) == T::from(3) { result = -result; }
+
+        a = a % n.clone();
+    }
+
+    return if n.is_one() { result } else { 0 };
+}
+
+// Legendre symbol (a/p) for an odd prime p
+pub fn legendre<T: Integer>(a: &T, p: &T) -> i32
+{
+    jacobi(a, p)
+}
+
+// One of the square roots of a modulo the prime p (Tonelli-Shanks), the other is p - r
+pub fn sqrt_mod<T: Integer>(a: &T, p: &T) -> Option<T>
+{
+    let a = a.clone() % p.clone();
+
+    if a.is_zero() { return Some(a); }
+    if *p == T::from(2) { return Some(a); }
+    if legendre(&a, p) != 1 { return None; }
+
+    // p - 1 = q * 2^s with q odd
+    let p_1 = p.clone() - T::one();
+    let s = p_1.trailing_zeros();
+    let q = p_1 >> s;
+
+    if s == 1
+    {
+        return Some(a.pow_mod(&((p.clone() + T::one()) >> 2), p));
+    }
+
+    // Any quadratic non-residue will do
+    let mut z = T::from(2);
+    while legendre(&z, p) != -1 { z = z + T::one(); }
+
+    let mut m = s;
+    let mut c = z.pow_mod(&q, p);
+    let mut t = a.pow_mod(&q, p);
+    let mut r = a.pow_mod(&((q + T::one()) >> 1), p);
+
+    while !t.is_one()
+    {
+        // Least i such that t^(2^i) = 1
+        let mut i = 0;
+        let mut t2 = t.clone();
+
+        while !t2.is_one()
+        {
+            t2 = t2.mul_mod(&t2, p);
+            i += 1;
+        }
+
+        let mut b = c;
+        for _ in 0..m - i - 1 { b = b.mul_mod(&b, p); }
+
+        m = i;
+        c = b.mul_mod(&b, p);
+        t = t.mul_mod(&c, p);
+        r = r.mul_mod(&b, p);
+    }
+
+    return Some(r);
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::num::{BigUint, BigInt};
+
+    #[test]
+    fn egcd()
+    {
+        assert_eq!((2, -9, 47), super::egcd(&240u64, &46));
+
+        let (g, x, y) = super::egcd(&BigUint::from(240u32), &BigUint::from(46u32));
+
+        assert_eq!(BigUint::from(2u32), g);
+        assert_eq!((BigInt::from(-9i64), BigInt::from(47i64)), (x, y));
+    }
+
+    #[test]
+    fn invmod()
+    {
+        assert_eq!(Some(2753), super::invmod(&17u64, &3120));
+        assert_eq!(None, super::invmod(&6u64, &9));
+
+        let p = (BigUint::one() << 127) - 1;
+        let a = BigUint::from(123456789u32);
+        let inverse = super::invmod(&a, &p).unwrap();
+
+        assert!((&(&a * &inverse) % &p).is_one());
+    }
+
+    #[test]
+    fn crt()
+    {
+        assert_eq!(Some((39, 60)), super::crt(&[(3u64, 4), (4, 5), (0, 3)]));
+        assert_eq!(None, super::crt(&[(1u64, 4), (3, 6)]));
+
+        // Hastad's broadcast attack setup: m^3 recovered from three residues
+        let m = BigUint::from(42u32).pow(3);
+        let moduli = [BigUint::from(1000003u32), BigUint::from(1000033u32), BigUint::from(1000037u32)];
+        let congruences: Vec<_> = moduli.iter().map( |n| (&m % n, n.clone()) ).collect();
+
+        assert_eq!(m, super::crt(&congruences).unwrap().0);
+    }
+
+    #[test]
+    fn jacobi()
+    {
+        assert_eq!(-1, super::jacobi(&1001u64, &9907));
+        assert_eq!(1, super::jacobi(&19u64, &45));
+        assert_eq!(0, super::jacobi(&6u64, &21));
+        assert_eq!(-1, super::legendre(&BigUint::from(5u32), &BigUint::from(23u32)));
+    }
+
+    #[test]
+    fn sqrt_mod()
+    {
+        // 10009 - 1 = 2^3 * 1251 goes through the Tonelli-Shanks loop
+        for a in 1..200u64
+        {
+            match super::sqrt_mod(&a, &10009)
+            {
+                Some(r) => assert_eq!(a, r * r % 10009),
+                None    => assert_eq!(-1, super::legendre(&a, &10009))
+            }
+        }
+
+        assert_eq!(None, super::sqrt_mod(&3u64, &7));
+        assert_eq!(Some(BigUint::from(2u32)), super::sqrt_mod(&BigUint::from(4u32), &BigUint::from(7u32)));
+    }
+}
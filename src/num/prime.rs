@@ -0,0 +1,159 @@
+use rand::Rng;
+
+use super::Integer;
+
+const SMALL_PRIMES: [u32; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+    73, 79, 83, 89, 97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173,
+    179, 181, 191, 193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251
+];
+
+// Testing these bases is enough to be deterministic below 2^64
+const U64_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// Miller-Rabin rounds used for prime generation (error below 2^-80)
+const GENERATION_ROUNDS: usize = 40;
+
+// Some(result) if n is small or has a small factor
+fn trial_division<T: Integer>(n: &T) -> Option<bool>
+{
+    for p in SMALL_PRIMES.iter()
+    {
+        let p = T::from(*p);
+
+        if *n == p { return Some(true); }
+        if (n.clone() % p).is_zero() { return Some(false); }
+    }
+
+    if *n < T::from(2) { return Some(false); }
+    if *n < T::from(256 * 256) { return Some(true); }
+
+    return None;
+}
+
+// Strong probable-prime test of an odd n > 2 to every base
+pub fn miller_rabin<T: Integer>(n: &T, bases: &[T]) -> bool
+{
+    // n - 1 = d * 2^s with d odd
+    let n_1 = n.clone() - T::one();
+    let s = n_1.trailing_zeros();
+    let d = n_1.clone() >> s;
+
+    'bases: for base in bases
+    {
+        let a = base.clone() % n.clone();
+        if a.is_zero() { continue; }
+
+        let mut x = a.pow_mod(&d, n);
+        if x.is_one() || x == n_1 { continue; }
+
+        for _ in 1..s
+        {
+            x = x.mul_mod(&x, n);
+            if x == n_1 { continue 'bases; }
+        }
+
+        return false;
+    }
+
+    return true;
+}
+
+// Deterministic primality test for u64
+pub fn is_prime(n: u64) -> bool
+{
+    match trial_division(&n)
+    {
+        Some(result) => result,
+        None         => miller_rabin(&n, &U64_BASES)
+    }
+}
+
+pub fn is_probable_prime<T: Integer, R: Rng + ?Sized>(n: &T, rounds: usize, rng: &mut R) -> bool
+{
+    if let Some(result) = trial_division(n) { return result; }
+
+    // Random bases in [2, n - 2]
+    let bound = n.clone() - T::from(3);
+    let bases: Vec<T> = (0..rounds).map( |_| T::random_below(&bound, rng) + T::from(2) ).collect();
+
+    return miller_rabin(n, &bases);
+}
+
+// Random prime of exactly *bits* bits
+pub fn random_prime<T: Integer, R: Rng + ?Sized>(bits: usize, rng: &mut R) -> T
+{
+    if bits < 2 { panic!("A prime needs at least 2 bits"); }
+
+    loop
+    {
+        let mut candidate = T::random_bits(bits - 1, rng) + (T::one() << (bits - 1));
+        if candidate.is_even() { candidate = candidate + T::one(); }
+
+        if is_probable_prime(&candidate, GENERATION_ROUNDS, rng) { return candidate; }
+    }
+}
+
+// Random prime p of exactly *bits* bits such that (p - 1) / 2 is prime too
+pub fn random_safe_prime<T: Integer, R: Rng + ?Sized>(bits: usize, rng: &mut R) -> T
+{
+    if bits < 3 { panic!("A safe prime needs at least 3 bits"); }
+
+    loop
+    {
+        let q: T = random_prime(bits - 1, rng);
+        let p = (q << 1) + T::one();
+
+        if trial_division(&p) != Some(false) && is_probable_prime(&p, GENERATION_ROUNDS, rng)
+        {
+            return p;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::num::BigUint;
+
+    #[test]
+    fn is_prime()
+    {
+        let primes: Vec<u64> = (0..100).filter( |n| super::is_prime(*n) ).collect();
+
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97], primes);
+        assert!(super::is_prime(18446744073709551557));
+        // Strong pseudoprime to bases 2 to 23
+        assert!(!super::is_prime(3825123056546413051));
+        // Carmichael number
+        assert!(!super::is_prime(561));
+    }
+
+    #[test]
+    fn is_probable_prime()
+    {
+        let mut rng = rand::thread_rng();
+        let m127 = (BigUint::one() << 127) - 1;
+        let m128 = (BigUint::one() << 128) - 1;
+
+        assert!(super::is_probable_prime(&m127, 20, &mut rng));
+        assert!(!super::is_probable_prime(&m128, 20, &mut rng));
+    }
+
+    #[test]
+    fn random_prime()
+    {
+        let mut rng = rand::thread_rng();
+
+        let p: BigUint = super::random_prime(128, &mut rng);
+        assert_eq!(128, p.bits());
+        assert!(super::is_probable_prime(&p, 20, &mut rng));
+
+        let p: u64 = super::random_prime(40, &mut rng);
+        assert!(super::is_prime(p));
+
+        let p: u64 = super::random_safe_prime(32, &mut rng);
+        assert_eq!(32, 64 - p.leading_zeros());
+        assert!(super::is_prime(p) && super::is_prime((p - 1) / 2));
+    }
+}
@@ -0,0 +1,79 @@
+use super::Integer;
+
+// x^e, or None as soon as it exceeds limit
+fn capped_pow<T: Integer>(x: &T, e: u32, limit: &T) -> Option<T>
+{
+    let mut acc = T::one();
+
+    for _ in 0..e
+    {
+        if acc > limit.clone() / x.clone() { return None; }
+        acc = acc * x.clone();
+    }
+
+    return Some(acc);
+}
+
+// Floor of the k-th root of n
+pub fn nth_root<T: Integer>(n: &T, k: u32) -> T
+{
+    if k == 0 { panic!("Root degree must not be zero"); }
+    if n.is_zero() || k == 1 { return n.clone(); }
+
+    // Start above the root and walk down with Newton's iteration
+    let mut x = T::one() << n.bits().div_ceil(k as usize);
+
+    loop
+    {
+        let quotient = match capped_pow(&x, k - 1, n)
+        {
+            Some(p) => n.clone() / p,
+            None    => T::zero()
+        };
+
+        // The next step wouldn't go down anymore
+        if quotient >= x { return x; }
+
+        x = (T::from(k - 1) * x + quotient) / T::from(k);
+    }
+}
+
+// k-th root of n if n is a perfect k-th power
+pub fn nth_root_exact<T: Integer>(n: &T, k: u32) -> Option<T>
+{
+    let root = nth_root(n, k);
+
+    match capped_pow(&root, k, n)
+    {
+        Some(p) if p == *n => Some(root),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::num::BigUint;
+
+    #[test]
+    fn nth_root()
+    {
+        assert_eq!(4294967295, super::nth_root(&u64::MAX, 2));
+        assert_eq!(2642245, super::nth_root(&u64::MAX, 3));
+        assert_eq!(1, super::nth_root(&u64::MAX, 64));
+        assert_eq!(2, super::nth_root(&80u64, 4));
+        assert_eq!(0, super::nth_root(&0u64, 3));
+
+        let m = "12345678901234567890123456789".parse::<BigUint>().unwrap();
+
+        assert_eq!(m, super::nth_root(&m.pow(3), 3));
+        assert_eq!(m, super::nth_root(&(m.pow(3) + m.pow(2)), 3));
+    }
+
+    #[test]
+    fn nth_root_exact()
+    {
+        assert_eq!(Some(12), super::nth_root_exact(&1728u64, 3));
+        assert_eq!(None, super::nth_root_exact(&1729u64, 3));
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+// Number of differing bits between two slices of the same length
+pub fn hamming_distance(a: &[u8], b: &[u8]) -> u32
+{
+    if a.len() != b.len() { panic!("Bytes must be the same length. ({}/{})", a.len(), b.len()); }
+
+    return a.iter().zip(b).map( |(x, y)| (x ^ y).count_ones() ).sum();
+}
+
+// Mean hamming distance per byte between the first *block_count* consecutive blocks,
+// None if the data doesn't hold two blocks
+pub fn normalized_hamming_distance(data: &[u8], block_size: usize, block_count: usize) -> Option<f64>
+{
+    let blocks: Vec<&[u8]> = data.chunks_exact(block_size).take(block_count).collect();
+    if blocks.len() < 2 { return None; }
+
+    let total_distance: u32 = blocks.windows(2).map( |w| hamming_distance(w[0], w[1]) ).sum();
+
+    return Some(total_distance as f64 / (block_size * (blocks.len() - 1)) as f64);
+}
+
+// Number of blocks that are a repetition of a previous block
+pub fn repeated_blocks(data: &[u8], block_size: usize) -> usize
+{
+    let mut seen = HashMap::<&[u8], usize>::new();
+
+    for block in data.chunks(block_size)
+    {
+        *seen.entry(block).or_insert(0) += 1;
+    }
+
+    return seen.values().map( |c| c - 1 ).sum();
+}
+
+// Share of repeated blocks (0 when every block is unique)
+pub fn block_repetition(data: &[u8], block_size: usize) -> f64
+{
+    let total = data.len().div_ceil(block_size);
+    if total == 0 { return 0.0; }
+
+    return repeated_blocks(data, block_size) as f64 / total as f64;
+}
+
+#[cfg(test)]
+mod tests
+{
+    #[test]
+    fn hamming_distance()
+    {
+        assert_eq!(37, super::hamming_distance(b"this is a test", b"wokka wokka!!!"));
+    }
+
+    #[test]
+    fn normalized_hamming_distance()
+    {
+        assert_eq!(Some(0.0), super::normalized_hamming_distance(b"abcabcabc", 3, 4));
+        assert_eq!(Some(37.0 / 14.0), super::normalized_hamming_distance(b"this is a testwokka wokka!!!", 14, 2));
+        assert_eq!(None, super::normalized_hamming_distance(b"abc", 3, 4));
+    }
+
+    #[test]
+    fn block_repetition()
+    {
+        assert_eq!(2, super::repeated_blocks(b"aaaabbbbaaaaaaaa", 4));
+        assert_eq!(0.5, super::block_repetition(b"aaaabbbbaaaaaaaa", 4));
+        assert_eq!(0.0, super::block_repetition(b"aaaabbbb", 4));
+        assert_eq!(0.0, super::block_repetition(b"", 4));
+    }
+}
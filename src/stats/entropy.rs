@@ -0,0 +1,31 @@
+use super::histogram;
+
+// Shannon entropy in bits per byte (0 for constant data, 8 for uniform data)
+pub fn shannon_entropy(data: &[u8]) -> f64
+{
+    let total = data.len() as f64;
+
+    return histogram(data).iter()
+        .filter( |c| **c > 0 )
+        .map( |c|
+        {
+            let p = *c as f64 / total;
+            -p * p.log2()
+        })
+        .sum();
+}
+
+#[cfg(test)]
+mod tests
+{
+    #[test]
+    fn shannon_entropy()
+    {
+        let all_bytes: Vec<u8> = (0..=255).collect();
+
+        assert_eq!(0.0, super::shannon_entropy(&[]));
+        assert_eq!(0.0, super::shannon_entropy(b"aaaa"));
+        assert_eq!(1.0, super::shannon_entropy(b"abab"));
+        assert_eq!(8.0, super::shannon_entropy(&all_bytes));
+    }
+}
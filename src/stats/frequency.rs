@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+// Relative frequencies of english letters (upper-cased) plus a few common symbols
+pub const ENGLISH_FREQUENCIES: [(char, f64); 30] = [
+    ('E', 0.11132),
+    ('T', 0.09356),
+    ('A', 0.08497),
+    ('R', 0.07587),
+    ('I', 0.07546),
+    ('O', 0.07507),
+    ('N', 0.06749),
+    ('S', 0.06327),
+    ('H', 0.06094),
+    ('D', 0.04253),
+    ('L', 0.04025),
+    ('U', 0.02758),
+    ('W', 0.02560),
+    ('M', 0.02406),
+    ('F', 0.02228),
+    ('C', 0.02202),
+    ('G', 0.02015),
+    ('Y', 0.01994),
+    ('P', 0.01929),
+    ('B', 0.01492),
+    ('K', 0.01292),
+    ('V', 0.00978),
+    ('J', 0.00153),
+    ('X', 0.00150),
+    ('Q', 0.00095),
+    ('Z', 0.00077),
+    // Add a little score to ponctuation
+    (' ', 0.01000),
+    ('.', 0.00002),
+    (',', 0.00002),
+    ('\n', 0.00001),
+];
+
+// Count of every byte value
+pub fn histogram(data: &[u8]) -> [usize; 256]
+{
+    let mut counts = [0; 256];
+
+    for b in data
+    {
+        counts[*b as usize] += 1;
+    }
+
+    return counts;
+}
+
+// Relative frequency of every char in the string
+pub fn char_frequencies(string: &str) -> HashMap<char, f64>
+{
+    let mut occurences = HashMap::<char, f64>::new();
+    let total_chars = string.chars().count() as f64;
+
+    for c in string.chars()
+    {
+        *occurences.entry(c).or_insert(0.0) += 1.0 / total_chars;
+    }
+
+    return occurences;
+}
+
+// Pearson's chi-squared of observed counts against expected probabilities
+// (categories with a null expectation are ignored)
+pub fn chi_squared(observed: &[usize], expected: &[f64]) -> f64
+{
+    if observed.len() != expected.len() { panic!("Distributions must be the same size. ({}/{})", observed.len(), expected.len()); }
+
+    let total: usize = observed.iter().sum();
+
+    return observed.iter()
+        .zip(expected)
+        .filter( |(_, p)| **p > 0.0 )
+        .map( |(o, p)|
+        {
+            let e = p * total as f64;
+            (*o as f64 - e).powi(2) / e
+        })
+        .sum();
+}
+
+// Chi-squared of the (upper-cased) text against a reference char distribution,
+// chars missing from the reference are not counted
+pub fn chi_squared_text(text: &str, reference: &[(char, f64)]) -> f64
+{
+    let total_ref: f64 = reference.iter().map( |r| r.1 ).sum();
+    let mut observed = vec![0; reference.len()];
+
+    for c in text.chars().flat_map(char::to_uppercase)
+    {
+        if let Some(i) = reference.iter().position( |r| r.0 == c )
+        {
+            observed[i] += 1;
+        }
+    }
+
+    let expected: Vec<f64> = reference.iter().map( |r| r.1 / total_ref ).collect();
+
+    return chi_squared(&observed, &expected);
+}
+
+// Probability that two bytes picked at random are equal
+pub fn index_of_coincidence(data: &[u8]) -> f64
+{
+    let n = data.len();
+    if n < 2 { return 0.0; }
+
+    let coincidences: usize = histogram(data).iter().map( |c| c * c.saturating_sub(1) ).sum();
+
+    return coincidences as f64 / (n * (n - 1)) as f64;
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashMap;
+
+    #[test]
+    fn histogram()
+    {
+        let counts = super::histogram(b"aabc");
+
+        assert_eq!(2, counts[b'a' as usize]);
+        assert_eq!(1, counts[b'c' as usize]);
+        assert_eq!(4, counts.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn char_frequencies()
+    {
+        let string = String::from("aabc");
+        let freqs: HashMap<char, f64> = [('a', 0.5), ('b', 0.25), ('c', 0.25)].iter().cloned().collect();
+
+        assert_eq!(freqs, super::char_frequencies(&string));
+    }
+
+    #[test]
+    fn chi_squared()
+    {
+        assert_eq!(0.0, super::chi_squared(&[25, 25, 50], &[0.25, 0.25, 0.5]));
+        assert_eq!(10.0, super::chi_squared(&[10, 30], &[0.5, 0.5]));
+
+        let reference = [('A', 0.5), ('B', 0.5)];
+
+        assert_eq!(0.0, super::chi_squared_text("ab ba", &reference));
+        assert!(super::chi_squared_text("aaaa", &reference) > 0.0);
+    }
+
+    #[test]
+    fn index_of_coincidence()
+    {
+        assert_eq!(1.0, super::index_of_coincidence(b"aaaa"));
+        assert_eq!(0.0, super::index_of_coincidence(b"abcd"));
+        assert_eq!(1.0 / 3.0, super::index_of_coincidence(b"aabb"));
+    }
+}
@@ -0,0 +1,9 @@
+mod frequency;
+mod entropy;
+mod distance;
+mod scorer;
+
+pub use frequency::{ENGLISH_FREQUENCIES, histogram, char_frequencies, chi_squared, chi_squared_text, index_of_coincidence};
+pub use entropy::shannon_entropy;
+pub use distance::{hamming_distance, normalized_hamming_distance, repeated_blocks, block_repetition};
+pub use scorer::{PlaintextScorer, Unigram, NGram, PrintableAscii, CorpusModel};
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::ENGLISH_FREQUENCIES;
+
+// Probability given to symbols never seen by a model
+const UNSEEN: f64 = 1e-6;
+
+// Short english sample used to build the default n-gram models
+const ENGLISH_SAMPLE: &str = "\
+It was the best of times and the worst of times for the people of the small town by the river. \
+Every morning the baker opened his shop before the sun was up, and the smell of fresh bread would \
+drift along the streets where children were walking to school. Their parents worked in the fields, \
+at the mill or in the old station, and in the evening they would meet at the inn to share the news \
+of the day. Nobody there had ever thought that anything could change, but that year a stranger came \
+with a letter for the mayor, and what was written in that letter would be talked about for a very \
+long time. Some said that he was a friend of the family who had left many years ago; others thought \
+that he was only looking for work and a place to stay. The truth, as is often the case, was somewhere \
+in between, and it would take the whole summer for the story to come out. When it did, the people \
+of the town found that they had much more in common with him than they would have liked to believe. \
+There is nothing new under the sun, the old teacher told them, and she was right: the same things \
+that happen in the great cities of the world happen in the smallest of villages, only more slowly.";
+
+// Higher scores mean more plausible plaintexts
+pub trait PlaintextScorer
+{
+    fn score(&self, plaintext: &[u8]) -> f64;
+}
+
+impl<F: Fn(&[u8]) -> f64> PlaintextScorer for F
+{
+    fn score(&self, plaintext: &[u8]) -> f64
+    {
+        self(plaintext)
+    }
+}
+
+// Mean log-likelihood of every byte under a single-byte distribution
+#[derive(Clone)]
+pub struct Unigram
+{
+    log_probs: [f64; 256]
+}
+
+impl Unigram
+{
+    // Letters are given the same probability in both cases
+    pub fn from_distribution(distribution: &[(char, f64)]) -> Unigram
+    {
+        let total: f64 = distribution.iter().map( |d| d.1 ).sum();
+        let mut log_probs = [UNSEEN.ln(); 256];
+
+        for (c, p) in distribution.iter().filter( |d| d.0.is_ascii() )
+        {
+            let log_prob = (p / total).ln();
+
+            log_probs[c.to_ascii_uppercase() as usize] = log_prob;
+            log_probs[c.to_ascii_lowercase() as usize] = log_prob;
+        }
+
+        return Unigram { log_probs: log_probs };
+    }
+
+    pub fn english() -> Unigram
+    {
+        Unigram::from_distribution(&ENGLISH_FREQUENCIES)
+    }
+
+    // Raw byte distribution of the corpus (add-one smoothed)
+    pub fn train(corpus: &[u8]) -> Unigram
+    {
+        let total = (corpus.len() + 256) as f64;
+        let mut log_probs = [0.0; 256];
+
+        for (i, count) in super::histogram(corpus).iter().enumerate()
+        {
+            log_probs[i] = ((count + 1) as f64 / total).ln();
+        }
+
+        return Unigram { log_probs: log_probs };
+    }
+}
+
+impl PlaintextScorer for Unigram
+{
+    fn score(&self, plaintext: &[u8]) -> f64
+    {
+        if plaintext.is_empty() { return UNSEEN.ln(); }
+
+        let total: f64 = plaintext.iter().map( |b| self.log_probs[*b as usize] ).sum();
+
+        return total / plaintext.len() as f64;
+    }
+}
+
+// Mean log-likelihood of every (case-insensitive) n-gram
+#[derive(Clone)]
+pub struct NGram
+{
+    n:         usize,
+    log_probs: HashMap<Vec<u8>, f64>,
+    floor:     f64
+}
+
+impl NGram
+{
+    pub fn train(n: usize, corpus: &[u8]) -> NGram
+    {
+        if n == 0 { panic!("N-gram size must not be null"); }
+
+        let corpus = corpus.to_ascii_lowercase();
+        let mut counts = HashMap::<Vec<u8>, usize>::new();
+
+        for gram in corpus.windows(n)
+        {
+            *counts.entry(gram.to_vec()).or_insert(0) += 1;
+        }
+
+        let total = counts.values().sum::<usize>().max(1) as f64;
+        let log_probs = counts.into_iter()
+            .map( |(gram, count)| (gram, (count as f64 / total).ln()) )
+            .collect();
+
+        return NGram {
+            n:         n,
+            log_probs: log_probs,
+            floor:     (UNSEEN / total).ln()
+        };
+    }
+
+    pub fn english_bigrams() -> NGram
+    {
+        NGram::train(2, ENGLISH_SAMPLE.as_bytes())
+    }
+
+    pub fn english_trigrams() -> NGram
+    {
+        NGram::train(3, ENGLISH_SAMPLE.as_bytes())
+    }
+
+    pub fn n(&self) -> usize
+    {
+        self.n
+    }
+}
+
+impl PlaintextScorer for NGram
+{
+    fn score(&self, plaintext: &[u8]) -> f64
+    {
+        if plaintext.len() < self.n { return self.floor; }
+
+        let plaintext = plaintext.to_ascii_lowercase();
+        let grams = plaintext.windows(self.n);
+        let count = grams.len() as f64;
+
+        let total: f64 = grams
+            .map( |g| *self.log_probs.get(g).unwrap_or(&self.floor) )
+            .sum();
+
+        return total / count;
+    }
+}
+
+// Share of printable ASCII bytes (whitespace included), in [0, 1]
+#[derive(Clone, Copy, Default)]
+pub struct PrintableAscii;
+
+impl PlaintextScorer for PrintableAscii
+{
+    fn score(&self, plaintext: &[u8]) -> f64
+    {
+        if plaintext.is_empty() { return 0.0; }
+
+        let printable = plaintext.iter()
+            .filter( |b| b.is_ascii_graphic() || matches!(b, b' ' | b'\t' | b'\n' | b'\r') )
+            .count();
+
+        return printable as f64 / plaintext.len() as f64;
+    }
+}
+
+// Byte unigram and bigram models trained on a reference text
+#[derive(Clone)]
+pub struct CorpusModel
+{
+    unigram: Unigram,
+    bigram:  NGram
+}
+
+impl CorpusModel
+{
+    pub fn train(corpus: &[u8]) -> CorpusModel
+    {
+        CorpusModel {
+            unigram: Unigram::train(corpus),
+            bigram:  NGram::train(2, corpus)
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<CorpusModel>
+    {
+        Ok(CorpusModel::train(&fs::read(path)?))
+    }
+}
+
+impl PlaintextScorer for CorpusModel
+{
+    fn score(&self, plaintext: &[u8]) -> f64
+    {
+        self.unigram.score(plaintext) + self.bigram.score(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::{PlaintextScorer, Unigram, NGram, PrintableAscii, CorpusModel};
+
+    const ENGLISH: &[u8] = b"Cooking MC's like a pound of bacon";
+    const XORED: &[u8] = b"\x1b77316?x\x15\x1b\x7f+x413=x9x(7-6<x7>x:9;76";
+
+    #[test]
+    fn unigram()
+    {
+        let scorer = Unigram::english();
+
+        assert!(scorer.score(ENGLISH) > scorer.score(XORED));
+        assert_eq!(scorer.score(b"ETAOIN"), scorer.score(b"etaoin"));
+    }
+
+    #[test]
+    fn ngram()
+    {
+        let bigrams  = NGram::english_bigrams();
+        let trigrams = NGram::english_trigrams();
+
+        assert_eq!(3, trigrams.n());
+        assert!(bigrams.score(ENGLISH) > bigrams.score(XORED));
+        assert!(trigrams.score(b"the other thing") > trigrams.score(b"teh toreh tnihg"));
+    }
+
+    #[test]
+    fn printable_ascii()
+    {
+        assert_eq!(1.0, PrintableAscii.score(ENGLISH));
+        assert_eq!(0.5, PrintableAscii.score(b"ab\x00\xFF"));
+        assert_eq!(0.0, PrintableAscii.score(b""));
+    }
+
+    #[test]
+    fn corpus_model()
+    {
+        // Latin-1 text isn't valid UTF-8 but must still be scored
+        let scorer = CorpusModel::train(b"d\xe9j\xe0 vu, \xe0 la fa\xe7on de l'\xe9t\xe9 d\xe9j\xe0 pass\xe9");
+
+        assert!(scorer.score(b"l'\xe9t\xe9 pass\xe9") > scorer.score(b"\x01\x02\x03\x04\x05\x06\x07"));
+    }
+
+    #[test]
+    fn closure()
+    {
+        let scorer = |p: &[u8]| p.len() as f64;
+
+        assert_eq!(3.0, scorer.score(b"abc"));
+    }
+}
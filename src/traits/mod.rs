@@ -0,0 +1,64 @@
+use crate::iterators::BlockIterator;
+use crate::types::{BitVec, BitOrder, CodecError};
+
+use rand::Rng;
+
+pub trait Bitable
+{
+    // Big-endian (bit 0 is the most significant bit)
+    fn bit(&self, n: usize) -> u8;
+    fn bits_in(&self, order: BitOrder) -> BitVec;
+
+    fn bits(&self) -> BitVec
+    {
+        self.bits_in(BitOrder::Msb0)
+    }
+}
+
+pub trait FromHex: Sized
+{
+    fn try_from_hex(string: &str) -> Result<Self, CodecError>;
+
+    fn from_hex(string: &str) -> Self
+    {
+        match Self::try_from_hex(string)
+        {
+            Ok(value) => value,
+            Err(e) => panic!("Invalid hex string ({})", e)
+        }
+    }
+}
+
+pub trait ToHex
+{
+    fn to_hex(&self) -> String;
+}
+
+pub trait FromBase64: Sized
+{
+    fn try_from_base64(string: &str) -> Result<Self, CodecError>;
+
+    fn from_base64(string: &str) -> Self
+    {
+        match Self::try_from_base64(string)
+        {
+            Ok(value) => value,
+            Err(e) => panic!("Malformed base64 string ({})", e)
+        }
+    }
+}
+
+pub trait ToBase64
+{
+    fn to_base64(&self) -> String;
+}
+
+pub trait BlockIterable
+{
+    fn blocks(&self, block_size: usize) -> BlockIterator;
+}
+
+pub trait Random
+{
+    fn random<R: Rng + ?Sized>(size: usize, rng: &mut R) -> Self;
+}
\ No newline at end of file
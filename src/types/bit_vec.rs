@@ -0,0 +1,404 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitXor, BitXorAssign, Not, Range};
+use std::str::FromStr;
+
+const WORD_SIZE: usize = 64;
+
+// Order of the bits inside a byte when converting from/to bytes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BitOrder
+{
+    // Bit 0 is the most significant bit of the first byte (big-endian)
+    Msb0,
+    // Bit 0 is the least significant bit of the first byte (little-endian)
+    Lsb0
+}
+
+// Packed bit vector, bit i is stored in words[i / 64] at position i % 64
+#[derive(Clone, Default)]
+pub struct BitVec
+{
+    words: Vec<u64>,
+    len:   usize
+}
+
+impl BitVec
+{
+    pub fn new() -> Self
+    {
+        BitVec { words: vec![], len: 0 }
+    }
+
+    pub fn zeros(len: usize) -> Self
+    {
+        BitVec {
+            words: vec![0; len.div_ceil(WORD_SIZE)],
+            len:   len
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8], order: BitOrder) -> Self
+    {
+        let mut bits = Self::zeros(bytes.len() * 8);
+
+        for (i, byte) in bytes.iter().enumerate()
+        {
+            let byte = match order
+            {
+                BitOrder::Msb0 => byte.reverse_bits(),
+                BitOrder::Lsb0 => *byte
+            };
+
+            bits.words[i / 8] |= (byte as u64) << (8 * (i % 8));
+        }
+
+        bits
+    }
+
+    // Pack the bits into bytes (the last byte is zero-padded)
+    pub fn to_bytes(&self, order: BitOrder) -> Vec<u8>
+    {
+        (0..self.len.div_ceil(8))
+            .map( |i| {
+                let byte = (self.words[i / 8] >> (8 * (i % 8))) as u8;
+
+                match order
+                {
+                    BitOrder::Msb0 => byte.reverse_bits(),
+                    BitOrder::Lsb0 => byte
+                }
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    pub fn get(&self, n: usize) -> bool
+    {
+        if n >= self.len { panic!("Out of range"); }
+
+        (self.words[n / WORD_SIZE] >> (n % WORD_SIZE)) & 0x1 == 0x1
+    }
+
+    pub fn set(&mut self, n: usize, value: bool)
+    {
+        if n >= self.len { panic!("Out of range"); }
+
+        let mask = 1 << (n % WORD_SIZE);
+
+        if value { self.words[n / WORD_SIZE] |= mask; }
+        else     { self.words[n / WORD_SIZE] &= !mask; }
+    }
+
+    pub fn flip(&mut self, n: usize)
+    {
+        let value = self.get(n);
+        self.set(n, !value);
+    }
+
+    pub fn push(&mut self, value: bool)
+    {
+        if self.len.is_multiple_of(WORD_SIZE) { self.words.push(0); }
+
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_
+    {
+        (0..self.len).map( move |i| self.get(i) )
+    }
+
+    pub fn count_ones(&self) -> usize
+    {
+        self.words.iter().map( |w| w.count_ones() as usize ).sum()
+    }
+
+    pub fn count_zeros(&self) -> usize
+    {
+        self.len - self.count_ones()
+    }
+
+    pub fn hamming_distance(&self, other: &BitVec) -> usize
+    {
+        (self ^ other).count_ones()
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> BitVec
+    {
+        if range.start > range.end || range.end > self.len { panic!("Out of range"); }
+
+        let len = range.end - range.start;
+
+        let mut bits = BitVec {
+            words: (0..len.div_ceil(WORD_SIZE)).map( |k| self.read_word((range.start + k * WORD_SIZE) as isize) ).collect(),
+            len:   len
+        };
+
+        bits.mask_tail();
+        bits
+    }
+
+    // Bit i takes the value of bit i+n (towards index 0, like a big-endian integer shift)
+    pub fn shl(&self, n: usize) -> BitVec
+    {
+        self.shifted(n as isize)
+    }
+
+    // Bit i takes the value of bit i-n
+    pub fn shr(&self, n: usize) -> BitVec
+    {
+        self.shifted(-(n.min(self.len) as isize))
+    }
+
+    pub fn rotate_left(&self, n: usize) -> BitVec
+    {
+        if self.is_empty() { return self.clone(); }
+
+        let n = n % self.len;
+
+        self.shl(n) ^ &self.shr(self.len - n)
+    }
+
+    pub fn rotate_right(&self, n: usize) -> BitVec
+    {
+        if self.is_empty() { return self.clone(); }
+
+        self.rotate_left(self.len - n % self.len)
+    }
+
+    // Same length, bit i takes the value of bit i+offset (zero outside)
+    fn shifted(&self, offset: isize) -> BitVec
+    {
+        let mut bits = BitVec {
+            words: (0..self.words.len()).map( |k| self.read_word(offset + (k * WORD_SIZE) as isize) ).collect(),
+            len:   self.len
+        };
+
+        bits.mask_tail();
+        bits
+    }
+
+    // Read the 64 bits starting at index start (bits outside of the vector are zero)
+    fn read_word(&self, start: isize) -> u64
+    {
+        let word = |i: isize| if i < 0 { 0 } else { *self.words.get(i as usize).unwrap_or(&0) };
+
+        let index  = start.div_euclid(WORD_SIZE as isize);
+        let offset = start.rem_euclid(WORD_SIZE as isize) as u32;
+
+        if offset == 0 { return word(index); }
+
+        word(index) >> offset | word(index + 1) << (WORD_SIZE as u32 - offset)
+    }
+
+    // Clear the unused bits of the last word (equality and popcount rely on it)
+    fn mask_tail(&mut self)
+    {
+        if !self.len.is_multiple_of(WORD_SIZE)
+        {
+            let last = self.words.len() - 1;
+            self.words[last] &= (1 << (self.len % WORD_SIZE)) - 1;
+        }
+    }
+}
+
+impl PartialEq for BitVec
+{
+    fn eq(&self, other: &BitVec) -> bool
+    {
+        self.len == other.len && self.words == other.words
+    }
+}
+
+impl Eq for BitVec {}
+
+impl FromIterator<bool> for BitVec
+{
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self
+    {
+        let mut bits = BitVec::new();
+
+        for b in iter
+        {
+            bits.push(b);
+        }
+
+        bits
+    }
+}
+
+// Parse a string of '0' and '1'
+impl FromStr for BitVec
+{
+    type Err = char;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err>
+    {
+        string.chars()
+            .map( |c| match c
+            {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(c)
+            })
+            .collect()
+    }
+}
+
+impl BitXorAssign<&BitVec> for BitVec
+{
+    fn bitxor_assign(&mut self, rhs: &BitVec)
+    {
+        if self.len != rhs.len { panic!("a(len={}) and b(len={}) must be of the same size.", self.len, rhs.len) }
+
+        for (a, b) in self.words.iter_mut().zip(&rhs.words)
+        {
+            *a ^= b;
+        }
+    }
+}
+
+impl BitXor<&BitVec> for BitVec
+{
+    type Output = BitVec;
+
+    fn bitxor(mut self, rhs: &BitVec) -> BitVec
+    {
+        self ^= rhs;
+        self
+    }
+}
+
+impl BitXor<&BitVec> for &BitVec
+{
+    type Output = BitVec;
+
+    fn bitxor(self, rhs: &BitVec) -> BitVec
+    {
+        self.clone() ^ rhs
+    }
+}
+
+impl Not for &BitVec
+{
+    type Output = BitVec;
+
+    fn not(self) -> BitVec
+    {
+        let mut bits = BitVec {
+            words: self.words.iter().map( |w| !w ).collect(),
+            len:   self.len
+        };
+
+        bits.mask_tail();
+        bits
+    }
+}
+
+impl fmt::Display for BitVec
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        for b in self.iter()
+        {
+            write!(f, "{}", b as u8)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BitVec
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn bits(s: &str) -> BitVec
+    {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn from_bytes()
+    {
+        assert_eq!(bits("0000000110000000"), BitVec::from_bytes(&[0x01, 0x80], BitOrder::Msb0));
+        assert_eq!(bits("1000000000000001"), BitVec::from_bytes(&[0x01, 0x80], BitOrder::Lsb0));
+
+        let bytes: Vec<u8> = (0..=255).collect();
+
+        for order in [BitOrder::Msb0, BitOrder::Lsb0].iter()
+        {
+            assert_eq!(bytes, BitVec::from_bytes(&bytes, *order).to_bytes(*order));
+        }
+
+        assert_eq!(vec![0xA0], bits("101").to_bytes(BitOrder::Msb0));
+    }
+
+    #[test]
+    fn count_ones()
+    {
+        let bytes = vec![0xff; 20];
+        let bits = BitVec::from_bytes(&bytes, BitOrder::Msb0);
+
+        assert_eq!(160, bits.count_ones());
+        assert_eq!(0, bits.count_zeros());
+        assert_eq!(4, bits.slice(3..7).count_ones());
+    }
+
+    #[test]
+    fn slice()
+    {
+        let bytes: Vec<u8> = (0..32).collect();
+        let all = BitVec::from_bytes(&bytes, BitOrder::Msb0);
+
+        assert_eq!(BitVec::from_bytes(&bytes[9..27], BitOrder::Msb0), all.slice(72..216));
+        assert_eq!(bits("011"), bits("10110").slice(1..4));
+    }
+
+    #[test]
+    fn shifts()
+    {
+        assert_eq!(bits("00110"), bits("10011").shl(1));
+        assert_eq!(bits("01001"), bits("10011").shr(1));
+        assert_eq!(bits("00000"), bits("10011").shl(7));
+        assert_eq!(bits("00111"), bits("10011").rotate_left(1));
+        assert_eq!(bits("11001"), bits("10011").rotate_right(1));
+
+        // Same as a big-endian integer shift with Msb0
+        let value: u128 = 0x0123456789abcdef_fedcba9876543210;
+        let all = BitVec::from_bytes(&value.to_be_bytes(), BitOrder::Msb0);
+
+        for n in [1, 13, 64, 71, 127].iter()
+        {
+            assert_eq!(BitVec::from_bytes(&(value << n).to_be_bytes(), BitOrder::Msb0), all.shl(*n));
+            assert_eq!(BitVec::from_bytes(&(value >> n).to_be_bytes(), BitOrder::Msb0), all.shr(*n));
+            assert_eq!(BitVec::from_bytes(&value.rotate_left(*n as u32).to_be_bytes(), BitOrder::Msb0), all.rotate_left(*n));
+        }
+    }
+
+    #[test]
+    fn xor()
+    {
+        assert_eq!(bits("0110"), &bits("1100") ^ &bits("1010"));
+        assert_eq!(bits("0011"), !&bits("1100"));
+        assert_eq!(2, bits("1100").hamming_distance(&bits("1010")));
+    }
+}
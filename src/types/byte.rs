@@ -1,4 +1,5 @@
-use crate::lib::traits::Bitable;
+use crate::traits::Bitable;
+use crate::types::{BitVec, BitOrder};
 
 pub type Byte = u8;
 
@@ -9,19 +10,12 @@ impl Bitable for Byte
         if n > 7 { panic!("Out of range"); }
 
         // Use Big-Endian for simplicity
-        ((self.reverse_bits() & 2u8.pow(n as u32)) >> n) as u8
+        (self.reverse_bits() & 2u8.pow(n as u32)) >> n
     }
 
-    fn bits(&self) -> Vec<u8>
+    fn bits_in(&self, order: BitOrder) -> BitVec
     { 
-        let mut bits = vec![];
-
-        for i in 0..8
-        {
-            bits.push(self.bit(i));
-        }
-
-        return bits;
+        BitVec::from_bytes(&[*self], order)
     }
 }
 
@@ -46,6 +40,15 @@ mod tests
         assert_eq!(1, byte.bit(7));
     }
 
+    #[test]
+    fn bits_in()
+    {
+        assert_eq!(Ok(super::BitVec::from_bytes(&[0x01], super::BitOrder::Msb0)), "00000001".parse());
+        assert_eq!(Ok(super::BitVec::from_bytes(&[0x01], super::BitOrder::Lsb0)), "10000000".parse());
+        assert_eq!("00000001", 0x01.bits().to_string());
+        assert_eq!("10000000", 0x01.bits_in(super::BitOrder::Lsb0).to_string());
+    }
+
     #[test]
     #[should_panic(expected = "Out of range")]
     fn bit_overflow()
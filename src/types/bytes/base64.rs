@@ -0,0 +1,484 @@
+use super::Bytes;
+use super::CodecError;
+use crate::traits::{FromBase64, ToBase64};
+
+const STANDARD_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const STANDARD_DECODING_TABLE: [u8; 256] = decoding_table(STANDARD_ALPHABET);
+const URL_SAFE_DECODING_TABLE: [u8; 256] = decoding_table(URL_SAFE_ALPHABET);
+
+const INVALID: u8 = 0xFF;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alphabet
+{
+    Standard,
+    UrlSafe
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Padding
+{
+    Required,
+    Optional,
+    Forbidden
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Base64
+{
+    alphabet:  Alphabet,
+    padding:   Padding,
+    line_wrap: Option<(usize, &'static str)>,
+    strict:    bool
+}
+
+impl Base64
+{
+    pub const STANDARD: Base64 = Base64::new(Alphabet::Standard, Padding::Required);
+    pub const URL_SAFE: Base64 = Base64::new(Alphabet::UrlSafe, Padding::Required);
+    // JWT, cookies
+    pub const URL_SAFE_NO_PAD: Base64 = Base64::new(Alphabet::UrlSafe, Padding::Forbidden);
+    // RFC 7468
+    pub const PEM: Base64 = Base64::new(Alphabet::Standard, Padding::Required).with_line_wrap(64, "\n");
+    // RFC 2045
+    pub const MIME: Base64 = Base64::new(Alphabet::Standard, Padding::Required).with_line_wrap(76, "\r\n");
+
+    pub const fn new(alphabet: Alphabet, padding: Padding) -> Self
+    {
+        Base64 {
+            alphabet:  alphabet,
+            padding:   padding,
+            line_wrap: None,
+            strict:    false
+        }
+    }
+
+    pub const fn with_padding(mut self, padding: Padding) -> Self
+    {
+        self.padding = padding;
+        self
+    }
+
+    pub const fn with_line_wrap(mut self, width: usize, line_ending: &'static str) -> Self
+    {
+        if width == 0 { panic!("Line width must not be zero"); }
+
+        self.line_wrap = Some((width, line_ending));
+        self
+    }
+
+    // Reject encodings with non-zero unused bits in the last sextet
+    pub const fn strict(mut self) -> Self
+    {
+        self.strict = true;
+        self
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> String
+    {
+        let table = self.encoding_table();
+        let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+        for chunk in bytes.chunks(3)
+        {
+            let b = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0)
+            ];
+
+            let sextets = [
+                b[0] >> 2,
+                (b[0] & 0x3) << 4 | b[1] >> 4,
+                (b[1] & 0xF) << 2 | b[2] >> 6,
+                b[2] & 0x3F
+            ];
+
+            // n octets are encoded into n+1 sextets (zero-padded)
+            for sextet in &sextets[..chunk.len() + 1]
+            {
+                output.push(table[*sextet as usize] as char);
+            }
+
+            if chunk.len() < 3 && self.padding != Padding::Forbidden
+            {
+                output.push_str(&"=".repeat(3 - chunk.len()));
+            }
+        }
+
+        match self.line_wrap
+        {
+            Some((width, line_ending)) => wrap(&output, width, line_ending),
+            None => output
+        }
+    }
+
+    pub fn decode(&self, string: &str) -> Result<Bytes, CodecError>
+    {
+        let mut decoder = Decoder::new(*self);
+        let mut result  = Bytes::with_capacity(string.len() * 3 / 4);
+
+        for (position, c) in string.chars().enumerate()
+        {
+            decoder.push(position, c, &mut result)?;
+        }
+
+        decoder.finish(&mut result)?;
+
+        Ok(result)
+    }
+
+    pub(crate) fn line_wrap(&self) -> Option<(usize, &'static str)>
+    {
+        self.line_wrap
+    }
+
+    pub(crate) fn without_line_wrap(mut self) -> Self
+    {
+        self.line_wrap = None;
+        self
+    }
+
+    fn encoding_table(&self) -> &'static [u8; 64]
+    {
+        match self.alphabet
+        {
+            Alphabet::Standard => STANDARD_ALPHABET,
+            Alphabet::UrlSafe  => URL_SAFE_ALPHABET
+        }
+    }
+
+    fn decoding_table(&self) -> &'static [u8; 256]
+    {
+        match self.alphabet
+        {
+            Alphabet::Standard => &STANDARD_DECODING_TABLE,
+            Alphabet::UrlSafe  => &URL_SAFE_DECODING_TABLE
+        }
+    }
+}
+
+// Incremental decoder, fed one char at a time (shared with the streaming reader)
+pub(crate) struct Decoder
+{
+    engine:        Base64,
+    table:         &'static [u8; 256],
+    // Data sextets (with their position) of the current 4-char group
+    group:         Vec<(usize, u8)>,
+    padding:       usize,
+    padding_start: Option<usize>,
+    length:        usize
+}
+
+impl Decoder
+{
+    pub(crate) fn new(engine: Base64) -> Self
+    {
+        Decoder {
+            engine:        engine,
+            table:         engine.decoding_table(),
+            group:         Vec::with_capacity(4),
+            padding:       0,
+            padding_start: None,
+            length:        0
+        }
+    }
+
+    pub(crate) fn push(&mut self, position: usize, c: char, output: &mut Bytes) -> Result<(), CodecError>
+    {
+        // Skip spaces and line returns
+        if c.is_ascii_whitespace() { return Ok(()); }
+
+        self.length += 1;
+
+        if c == '='
+        {
+            if self.engine.padding == Padding::Forbidden
+            {
+                return Err(CodecError::BadPadding { position: position });
+            }
+
+            // Padding after an already padded group
+            if self.group.is_empty() && self.padding == 0
+            {
+                if let Some(p) = self.padding_start
+                {
+                    return Err(CodecError::BadPadding { position: p });
+                }
+            }
+
+            self.padding_start.get_or_insert(position);
+            self.padding += 1;
+        }
+        else
+        {
+            // Data after padding
+            if let Some(p) = self.padding_start
+            {
+                return Err(CodecError::BadPadding { position: p });
+            }
+
+            let sextet = if c.is_ascii() { self.table[c as usize] } else { INVALID };
+
+            if sextet == INVALID
+            {
+                return Err(CodecError::InvalidChar { position: position, character: c });
+            }
+
+            self.group.push((position, sextet));
+        }
+
+        if self.group.len() + self.padding == 4
+        {
+            self.flush(output)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn finish(&mut self, output: &mut Bytes) -> Result<(), CodecError>
+    {
+        if self.group.is_empty() && self.padding == 0 { return Ok(()); }
+
+        if self.padding > 2
+        {
+            return Err(CodecError::BadPadding { position: self.padding_start.unwrap() });
+        }
+
+        // A single sextet cannot hold a full octet
+        if self.group.len() == 1 || (self.engine.padding == Padding::Required && !self.length.is_multiple_of(4))
+        {
+            return Err(CodecError::BadLength { length: self.length });
+        }
+
+        if self.padding > 0 && self.group.len() + self.padding != 4
+        {
+            return Err(CodecError::BadPadding { position: self.padding_start.unwrap() });
+        }
+
+        self.flush(output)
+    }
+
+    fn flush(&mut self, output: &mut Bytes) -> Result<(), CodecError>
+    {
+        if self.padding > 2
+        {
+            return Err(CodecError::BadPadding { position: self.padding_start.unwrap() });
+        }
+
+        let n = self.group.len();
+        let (position, last) = self.group[n - 1];
+
+        if self.engine.strict
+        {
+            let unused_mask = match n
+            {
+                2 => 0xF,
+                3 => 0x3,
+                _ => 0x0
+            };
+
+            if last & unused_mask != 0
+            {
+                return Err(CodecError::NonCanonical { position: position });
+            }
+        }
+
+        let mut sextets = [0u8; 4];
+
+        for (i, (_, sextet)) in self.group.iter().enumerate()
+        {
+            sextets[i] = *sextet;
+        }
+
+        let bytes = [
+            sextets[0] << 2 | sextets[1] >> 4,
+            (sextets[1] & 0xF) << 4 | sextets[2] >> 2,
+            (sextets[2] & 0x3) << 6 | sextets[3]
+        ];
+
+        // n sextets are decoded into n-1 octets
+        output.extend(&bytes[..n - 1]);
+
+        self.group.clear();
+        self.padding = 0;
+
+        Ok(())
+    }
+}
+
+impl Default for Base64
+{
+    fn default() -> Self
+    {
+        Self::STANDARD
+    }
+}
+
+impl FromBase64 for Bytes
+{
+    fn try_from_base64(string: &str) -> Result<Self, CodecError>
+    {
+        Base64::STANDARD.decode(string)
+    }
+}
+
+impl ToBase64 for Bytes
+{
+    fn to_base64(&self) -> String 
+    {
+        Base64::STANDARD.encode(self)
+    }
+}
+
+const fn decoding_table(alphabet: &[u8; 64]) -> [u8; 256]
+{
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+
+    while i < 64
+    {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+}
+
+fn wrap(string: &str, width: usize, line_ending: &str) -> String
+{
+    // Base64 output is pure ASCII, byte-slicing is safe
+    string.as_bytes()
+        .chunks(width)
+        .map( |line| std::str::from_utf8(line).unwrap() )
+        .collect::<Vec<&str>>()
+        .join(line_ending)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::traits::FromHex;
+
+    #[test]
+    fn to_base64()
+    {
+        let data = [
+            ("Test", "VGVzdA=="),
+            ("I'm killing your brain like a poisonous mushroom", "SSdtIGtpbGxpbmcgeW91ciBicmFpbiBsaWtlIGEgcG9pc29ub3VzIG11c2hyb29t")
+        ];
+
+        for kv in data.iter()
+        {
+            assert_eq!(kv.1, Bytes::from(kv.0).to_base64())
+        }
+    }
+
+    #[test]
+    fn from_base64()
+    {
+        let data = [
+            ("Test", "VGVzdA=="),
+            ("I'm killing your brain like a poisonous mushroom", "SSdtIGtpbGxpbmcgeW91ciBicmFpbiBsaWtlIGEgcG9pc29ub3VzIG11c2hyb29t"),
+            ("Lorem ipsum", "TG9yZW0g\naXBzdW0=")
+        ];
+
+        for kv in data.iter()
+        {
+            assert_eq!(
+                kv.0, 
+                Bytes::from_base64(kv.1).to_utf8().unwrap()
+            )
+        }
+    }
+
+    #[test]
+    fn try_from_base64()
+    {
+        assert_eq!(Ok(Bytes::from("Test")), Bytes::try_from_base64("VGVzdA==\r\n"));
+
+        assert_eq!(
+            Err(CodecError::InvalidChar { position: 10, character: '*' }),
+            Bytes::try_from_base64("VGVz\nVGVz\n*GVz")
+        );
+
+        assert_eq!(Err(CodecError::BadLength { length: 7 }), Bytes::try_from_base64("VGVzdA="));
+        assert_eq!(Err(CodecError::BadPadding { position: 6 }), Bytes::try_from_base64("VGVzdA=A"));
+        assert_eq!(Err(CodecError::BadPadding { position: 5 }), Bytes::try_from_base64("VGVzd==="));
+        assert_eq!(Err(CodecError::BadPadding { position: 4 }), Bytes::try_from_base64("VGVz===="));
+    }
+
+    #[test]
+    fn url_safe()
+    {
+        let bytes = Bytes::from_hex("fbff");
+
+        assert_eq!("+/8=", Base64::STANDARD.encode(&bytes));
+        assert_eq!("-_8=", Base64::URL_SAFE.encode(&bytes));
+        assert_eq!("-_8", Base64::URL_SAFE_NO_PAD.encode(&bytes));
+
+        assert_eq!(Ok(bytes.clone()), Base64::URL_SAFE_NO_PAD.decode("-_8"));
+        assert_eq!(
+            Err(CodecError::InvalidChar { position: 0, character: '+' }),
+            Base64::URL_SAFE_NO_PAD.decode("+/8")
+        );
+    }
+
+    #[test]
+    fn jwt_header()
+    {
+        let header = r#"{"alg":"HS256","typ":"JWT"}"#;
+        let encoded = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9";
+
+        assert_eq!(encoded, Base64::URL_SAFE_NO_PAD.encode(&Bytes::from(header)));
+        assert_eq!(Ok(Bytes::from(header)), Base64::URL_SAFE_NO_PAD.decode(encoded));
+    }
+
+    #[test]
+    fn padding()
+    {
+        let optional = Base64::STANDARD.with_padding(Padding::Optional);
+
+        assert_eq!(Ok(Bytes::from("Test")), optional.decode("VGVzdA"));
+        assert_eq!(Ok(Bytes::from("Test")), optional.decode("VGVzdA=="));
+        assert_eq!(Err(CodecError::BadPadding { position: 6 }), optional.decode("VGVzdA="));
+        assert_eq!(Err(CodecError::BadLength { length: 5 }), optional.decode("VGVzd"));
+
+        assert_eq!(Err(CodecError::BadLength { length: 6 }), Base64::STANDARD.decode("VGVzdA"));
+        assert_eq!(Err(CodecError::BadPadding { position: 6 }), Base64::URL_SAFE_NO_PAD.decode("VGVzdA=="));
+    }
+
+    #[test]
+    fn line_wrap()
+    {
+        let bytes = Bytes::from(&[0u8; 60][..]);
+
+        let pem = Base64::PEM.encode(&bytes);
+        let lines: Vec<&str> = pem.split('\n').collect();
+
+        assert_eq!(2, lines.len());
+        assert_eq!(64, lines[0].len());
+        assert_eq!(16, lines[1].len());
+
+        let mime = Base64::MIME.encode(&bytes);
+
+        assert_eq!(76, mime.find("\r\n").unwrap());
+        assert_eq!(Ok(bytes.clone()), Base64::MIME.decode(&mime));
+        assert_eq!(Ok(bytes), Base64::STANDARD.decode(&pem));
+    }
+
+    #[test]
+    fn strict()
+    {
+        // "VGVzdB==" decodes to "Test" with non-zero trailing bits
+        assert_eq!(Ok(Bytes::from("Test")), Base64::STANDARD.decode("VGVzdB=="));
+        assert_eq!(
+            Err(CodecError::NonCanonical { position: 5 }),
+            Base64::STANDARD.strict().decode("VGVzdB==")
+        );
+        assert_eq!(Ok(Bytes::from("Test")), Base64::STANDARD.strict().decode("VGVzdA=="));
+    }
+}
\ No newline at end of file
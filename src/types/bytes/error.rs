@@ -0,0 +1,44 @@
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodecError
+{
+    // Position is the char index in the original input (whitespace included)
+    InvalidChar  { position: usize, character: char },
+    OddLength    { length: usize },
+    BadLength    { length: usize },
+    BadPadding   { position: usize },
+    NonCanonical { position: usize }
+}
+
+impl CodecError
+{
+    pub fn position(&self) -> Option<usize>
+    {
+        match self
+        {
+            Self::InvalidChar { position, .. } => Some(*position),
+            Self::BadPadding { position }      => Some(*position),
+            Self::NonCanonical { position }    => Some(*position),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for CodecError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        match self
+        {
+            Self::InvalidChar { position, character } => write!(f, "invalid character {:?} at position {}", character, position),
+            Self::OddLength { length }                => write!(f, "odd length ({}), a byte is split", length),
+            Self::BadLength { length }                => write!(f, "bad length ({})", length),
+            Self::BadPadding { position }             => write!(f, "bad padding at position {}", position),
+            Self::NonCanonical { position }           => write!(f, "non-canonical trailing bits at position {}", position)
+        }
+    }
+}
+
+impl error::Error for CodecError {}
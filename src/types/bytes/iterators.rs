@@ -0,0 +1,11 @@
+use crate::traits::BlockIterable;
+use crate::types::Bytes;
+use crate::iterators::BlockIterator;
+
+impl BlockIterable for Bytes
+{
+    fn blocks(&self, block_size: usize) -> BlockIterator
+    { 
+        BlockIterator::new(self, block_size)
+    }
+}
\ No newline at end of file
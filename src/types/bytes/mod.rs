@@ -0,0 +1,477 @@
+pub mod base64;
+
+mod error;
+mod iterators;
+mod ops;
+
+pub use error::CodecError;
+pub use ops::Cycle;
+
+use std::borrow::Borrow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use std::string::FromUtf8Error;
+use std::vec;
+use std::slice;
+
+use crate::traits::{FromHex, Bitable, ToHex, Random};
+use crate::types::{BitVec, BitOrder};
+
+use rand::Rng;
+
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bytes(Vec<u8>);
+
+impl Bytes
+{
+    pub fn new() -> Self
+    {
+        Bytes(Vec::new())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self
+    {
+        Bytes(Vec::with_capacity(capacity))
+    }
+
+    pub fn to_utf8(&self) -> Result<String, FromUtf8Error>
+    {
+        String::from_utf8(self.0.clone())
+    }
+
+    pub fn into_vec(self) -> Vec<u8>
+    {
+        self.0
+    }
+
+    pub fn push(&mut self, byte: u8)
+    {
+        self.0.push(byte);
+    }
+
+    pub fn truncate(&mut self, len: usize)
+    {
+        self.0.truncate(len);
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.0.clear();
+    }
+
+    pub fn split_off(&mut self, at: usize) -> Bytes
+    {
+        Bytes(self.0.split_off(at))
+    }
+
+    pub fn resize(&mut self, len: usize, value: u8)
+    {
+        self.0.resize(len, value);
+    }
+}
+
+impl Deref for Bytes
+{
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8]
+    {
+        &self.0
+    }
+}
+
+impl DerefMut for Bytes
+{
+    fn deref_mut(&mut self) -> &mut [u8]
+    {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes
+{
+    fn as_ref(&self) -> &[u8]
+    {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Bytes
+{
+    fn as_mut(&mut self) -> &mut [u8]
+    {
+        &mut self.0
+    }
+}
+
+impl Borrow<[u8]> for Bytes
+{
+    fn borrow(&self) -> &[u8]
+    {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes
+{
+    fn from(bytes: Vec<u8>) -> Self
+    {
+        Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes
+{
+    fn from(bytes: &[u8]) -> Self
+    {
+        Bytes(bytes.to_vec())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Bytes
+{
+    fn from(bytes: [u8; N]) -> Self
+    {
+        Bytes(bytes.to_vec())
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for Bytes
+{
+    fn from(bytes: &[u8; N]) -> Self
+    {
+        Bytes(bytes.to_vec())
+    }
+}
+
+impl From<&str> for Bytes
+{
+    fn from(string: &str) -> Self
+    {
+        Bytes(string.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for Bytes
+{
+    fn from(string: String) -> Self
+    {
+        Bytes(string.into_bytes())
+    }
+}
+
+impl From<Bytes> for Vec<u8>
+{
+    fn from(bytes: Bytes) -> Self
+    {
+        bytes.0
+    }
+}
+
+impl TryFrom<Bytes> for String
+{
+    type Error = FromUtf8Error;
+
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error>
+    {
+        String::from_utf8(bytes.0)
+    }
+}
+
+// Parse an hex string
+impl FromStr for Bytes
+{
+    type Err = CodecError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err>
+    {
+        Self::try_from_hex(string)
+    }
+}
+
+impl FromIterator<u8> for Bytes
+{
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self
+    {
+        Bytes(iter.into_iter().collect())
+    }
+}
+
+impl Extend<u8> for Bytes
+{
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I)
+    {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a u8> for Bytes
+{
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I)
+    {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for Bytes
+{
+    type Item = u8;
+    type IntoIter = vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Bytes
+{
+    type Item = &'a u8;
+    type IntoIter = slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.0.iter()
+    }
+}
+
+impl PartialEq<[u8]> for Bytes
+{
+    fn eq(&self, other: &[u8]) -> bool
+    {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&[u8]> for Bytes
+{
+    fn eq(&self, other: &&[u8]) -> bool
+    {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Vec<u8>> for Bytes
+{
+    fn eq(&self, other: &Vec<u8>) -> bool
+    {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Bytes> for Vec<u8>
+{
+    fn eq(&self, other: &Bytes) -> bool
+    {
+        self == &other.0
+    }
+}
+
+impl fmt::LowerHex for Bytes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        for b in &self.0
+        {
+            write!(f, "{:0>2x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for Bytes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        for b in &self.0
+        {
+            write!(f, "{:0>2X}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Bytes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{:x}", self)
+    }
+}
+
+impl fmt::Debug for Bytes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "{:x}", self)
+    }
+}
+
+impl Bitable for Bytes
+{
+    fn bit(&self, n: usize) -> u8
+    { 
+        if n >= self.len() * 8 { panic!("Out of range"); }
+
+        self[n / 8].bit(n % 8)
+    }
+
+    fn bits_in(&self, order: BitOrder) -> BitVec
+    { 
+        BitVec::from_bytes(self, order)
+    }
+}
+
+impl Random for Bytes
+{
+    fn random<R: Rng + ?Sized>(size: usize, rng: &mut R) -> Self
+    {
+        let mut bytes = vec![0u8; size];
+        rng.fill_bytes(&mut bytes);
+
+        Bytes::from(bytes)
+    }
+}
+
+impl FromHex for Bytes
+{
+    fn try_from_hex(string: &str) -> Result<Self, CodecError>
+    {
+        let length = string.chars().count();
+
+        if !length.is_multiple_of(2)
+        {
+            return Err(CodecError::OddLength { length: length });
+        }
+
+        let mut nibbles = Vec::with_capacity(length);
+
+        for (position, c) in string.chars().enumerate()
+        {
+            match c.to_digit(16)
+            {
+                Some(n) => nibbles.push(n as u8),
+                None => return Err(CodecError::InvalidChar { position: position, character: c })
+            }
+        }
+
+        Ok(
+            nibbles.chunks(2)
+                .map( |pair| pair[0] << 4 | pair[1] )
+                .collect()
+        )
+    }
+}
+
+impl ToHex for Bytes
+{
+    fn to_hex(&self) -> String
+    {
+        format!("{:x}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn from_str()
+    {
+        let bytes = Bytes::from("Test");
+        let actual: Vec<u8> = bytes.into();
+
+        assert_eq!(vec![0x54, 0x65, 0x73, 0x74], actual);
+    }
+
+    #[test]
+    fn parse()
+    {
+        assert_eq!(Ok(Bytes::from(vec![0xde, 0xad])), "dead".parse::<Bytes>());
+        assert_eq!(Err(CodecError::OddLength { length: 3 }), "dea".parse::<Bytes>());
+    }
+
+    #[test]
+    fn random()
+    {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let a = Bytes::random(16, &mut StdRng::seed_from_u64(1337));
+        let b = Bytes::random(16, &mut StdRng::seed_from_u64(1337));
+
+        assert_eq!(16, a.len());
+        assert_eq!(a, b);
+        assert_ne!(a, Bytes::random(16, &mut StdRng::seed_from_u64(1338)));
+    }
+
+    #[test]
+    fn format()
+    {
+        let bytes = Bytes::from(vec![0x00, 0xab, 0x1f]);
+
+        assert_eq!("00ab1f", bytes.to_string());
+        assert_eq!("00ab1f", format!("{:?}", bytes));
+        assert_eq!("00ab1f", format!("{:x}", bytes));
+        assert_eq!("00AB1F", format!("{:X}", bytes));
+    }
+
+    #[test]
+    fn to_utf8()
+    {
+        assert_eq!(Ok(String::from("Test")), Bytes::from("Test").to_utf8());
+        assert!(Bytes::from(vec![0xff]).to_utf8().is_err());
+    }
+
+    #[test]
+    fn from_hex()
+    {
+        let bytes = Bytes::from_hex("0123456789abcdef");
+        let actual: Vec<u8> = bytes.into();
+
+        assert_eq!(vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef], actual);
+    }
+
+    #[test]
+    fn try_from_hex()
+    {
+        assert_eq!(Ok(Bytes::from(vec![0xab, 0xcd])), Bytes::try_from_hex("AbcD"));
+        assert_eq!(Err(CodecError::OddLength { length: 3 }), Bytes::try_from_hex("abc"));
+        assert_eq!(
+            Err(CodecError::InvalidChar { position: 2, character: 'g' }),
+            Bytes::try_from_hex("abgd")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid hex string")]
+    fn from_hex_invalid()
+    {
+        Bytes::from_hex("0x12");
+    }
+
+    #[test]
+    fn bits()
+    {
+        assert_eq!(
+            Ok(Bytes::from_hex("ff").bits()),
+            "11111111".parse()
+        );
+
+        assert_eq!(
+            Ok(Bytes::from_hex("10").bits()),
+            "00010000".parse()
+        );
+
+        assert_eq!(
+            Ok(Bytes::from_hex("10").bits_in(BitOrder::Lsb0)),
+            "00001000".parse()
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,151 @@
+use std::ops::{BitXor, BitXorAssign};
+
+use super::Bytes;
+
+// Right-hand side of a XOR repeated over the whole left-hand side (repeating-key XOR)
+#[derive(Debug, Clone, Copy)]
+pub struct Cycle<'a>(pub &'a [u8]);
+
+impl Bytes
+{
+    pub fn try_xor(&self, rhs: &[u8]) -> Option<Bytes>
+    {
+        if self.len() != rhs.len() { return None; }
+
+        Some(
+            self.iter().zip(rhs)
+                .map( |(a, b)| a ^ b )
+                .collect()
+        )
+    }
+}
+
+fn xor_assign(lhs: &mut [u8], rhs: &[u8])
+{
+    if lhs.len() != rhs.len() { panic!("a(len={}) and b(len={}) must be of the same size.", lhs.len(), rhs.len()) }
+
+    for (a, b) in lhs.iter_mut().zip(rhs)
+    {
+        *a ^= b;
+    }
+}
+
+fn cycle_xor_assign(lhs: &mut [u8], key: &[u8])
+{
+    if key.is_empty() { panic!("Key must not by empty"); }
+
+    for (a, b) in lhs.iter_mut().zip(key.iter().cycle())
+    {
+        *a ^= b;
+    }
+}
+
+impl BitXorAssign<&[u8]> for Bytes
+{
+    fn bitxor_assign(&mut self, rhs: &[u8])
+    {
+        xor_assign(self, rhs);
+    }
+}
+
+impl BitXorAssign<&Bytes> for Bytes
+{
+    fn bitxor_assign(&mut self, rhs: &Bytes)
+    {
+        xor_assign(self, rhs);
+    }
+}
+
+impl BitXorAssign<Cycle<'_>> for Bytes
+{
+    fn bitxor_assign(&mut self, rhs: Cycle)
+    {
+        cycle_xor_assign(self, rhs.0);
+    }
+}
+
+impl BitXorAssign<u8> for Bytes
+{
+    fn bitxor_assign(&mut self, rhs: u8)
+    {
+        cycle_xor_assign(self, &[rhs]);
+    }
+}
+
+// Implement the binary operators (owned and borrowed lhs) on top of the assign versions
+macro_rules! impl_xor
+{
+    ($($rhs:ty),*) => {
+        $(
+            impl BitXor<$rhs> for Bytes
+            {
+                type Output = Bytes;
+
+                fn bitxor(mut self, rhs: $rhs) -> Bytes
+                {
+                    self ^= rhs;
+                    self
+                }
+            }
+
+            impl BitXor<$rhs> for &Bytes
+            {
+                type Output = Bytes;
+
+                fn bitxor(self, rhs: $rhs) -> Bytes
+                {
+                    self.clone() ^ rhs
+                }
+            }
+        )*
+    }
+}
+
+impl_xor!(&[u8], &Bytes, Cycle<'_>, u8);
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::traits::FromHex;
+
+    #[test]
+    fn xor()
+    {
+        let a = Bytes::from_hex("1c0111001f010100061a024b53535009181c");
+        let b = Bytes::from_hex("686974207468652062756c6c277320657965");
+
+        assert_eq!(Bytes::from_hex("746865206b696420646f6e277420706c6179"), &a ^ &b);
+
+        let mut c = a.clone();
+        c ^= &b;
+        c ^= &b[..];
+
+        assert_eq!(a, c);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be of the same size")]
+    fn xor_mismatch()
+    {
+        let _ = Bytes::from("abc") ^ &Bytes::from("ab");
+    }
+
+    #[test]
+    fn try_xor()
+    {
+        assert_eq!(Some(Bytes::from(vec![0x3, 0x3])), Bytes::from(vec![0x1, 0x2]).try_xor(&[0x2, 0x1]));
+        assert_eq!(None, Bytes::from(vec![0x1, 0x2]).try_xor(&[0x2]));
+    }
+
+    #[test]
+    fn cycle()
+    {
+        assert_eq!(
+            Bytes::from_hex("0b3637272a2b2e63622c2e69692a23693a2a3c6324202d623d63343c2a26226324272765272a282b2f20"),
+            Bytes::from("Burning 'em, if you ain't quick and nimble") ^ Cycle(b"ICE")
+        );
+
+        assert_eq!(Bytes::from(vec![0x1, 0x1]), Bytes::from(vec![0x0, 0x0]) ^ 0x1);
+    }
+}
@@ -0,0 +1,468 @@
+use std::ops;
+use std::cmp;
+use std::fmt;
+
+use crate::math::Field;
+
+// Row-major matrix, m[i] is the i-th row
+pub struct Matrix<T>
+{
+    width:  usize,
+    height: usize,
+
+    values: Vec<T>
+}
+
+impl<T> Matrix<T> where T: Default + Copy
+{
+    pub fn new(height: usize, width: usize) -> Self
+    {
+        Matrix { width: width, height: height, values: vec![T::default(); width * height] }
+    }
+
+    pub fn width(&self) -> usize
+    {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize
+    {
+        return self.height;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]>
+    {
+        // chunks_exact(0) would panic on a Nx0 matrix
+        self.values.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, j: usize) -> Vec<T>
+    {
+        if j >= self.width { panic!("Out of range"); }
+
+        (0..self.height).map( |i| self[i][j] ).collect()
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize)
+    {
+        if a == b { return; }
+
+        let (a, b) = (a.min(b), a.max(b));
+        let (head, tail) = self.values.split_at_mut(b * self.width);
+
+        head[a * self.width..(a + 1) * self.width].swap_with_slice(&mut tail[..self.width]);
+    }
+
+    pub fn transpose(&self) -> Self
+    {
+        let mut transposed = Self::new(self.width, self.height);
+
+        for j in 0..self.width
+        {
+            for i in 0..self.height
+            {
+                transposed[j][i] = self[i][j];
+            }
+        }
+
+        return transposed;
+    }
+}
+
+impl<T> Matrix<T> where T: Field + Default
+{
+    pub fn identity(size: usize) -> Self
+    {
+        let mut identity = Self::new(size, size);
+
+        for i in 0..size
+        {
+            identity[i][i] = T::ONE;
+        }
+
+        return identity;
+    }
+
+    pub fn mul_vector(&self, vector: &[T]) -> Vec<T>
+    {
+        if vector.len() != self.width { panic!("Vector must have {} elements (got {})", self.width, vector.len()); }
+
+        self.rows()
+            .map( |row| row.iter().zip(vector).fold(T::ZERO, |acc, (a, b)| acc + *a * *b) )
+            .collect()
+    }
+
+    // Gauss-Jordan elimination to reduced row echelon form, returns the pivot columns
+    pub fn row_reduce(&mut self) -> Vec<usize>
+    {
+        let mut pivots = vec![];
+
+        for j in 0..self.width
+        {
+            let r = pivots.len();
+            if r == self.height { break; }
+
+            let p = match (r..self.height).find( |i| !self[*i][j].is_zero() )
+            {
+                Some(p) => p,
+                None    => continue
+            };
+
+            self.swap_rows(r, p);
+
+            let inverse = self[r][j].inv().unwrap();
+            for x in self[r].iter_mut() { *x = *x * inverse; }
+
+            for i in 0..self.height
+            {
+                let factor = self[i][j];
+                if i == r || factor.is_zero() { continue; }
+
+                for k in j..self.width
+                {
+                    let v = self[r][k];
+                    self[i][k] = self[i][k] - factor * v;
+                }
+            }
+
+            pivots.push(j);
+        }
+
+        return pivots;
+    }
+
+    pub fn rank(&self) -> usize
+    {
+        self.clone().row_reduce().len()
+    }
+
+    // None if the matrix is singular (or not square)
+    pub fn inverse(&self) -> Option<Self>
+    {
+        if self.width != self.height { return None; }
+
+        let n = self.width;
+        let mut augmented = self.augment(&Self::identity(n));
+
+        if augmented.row_reduce().iter().take_while( |j| **j < n ).count() != n { return None; }
+
+        let mut inverse = Self::new(n, n);
+
+        for i in 0..n
+        {
+            inverse[i].copy_from_slice(&augmented[i][n..]);
+        }
+
+        return Some(inverse);
+    }
+
+    // Basis of the right nullspace {x | Ax = 0}
+    pub fn kernel(&self) -> Vec<Vec<T>>
+    {
+        let mut reduced = self.clone();
+        let pivots = reduced.row_reduce();
+
+        // One basis vector per free column
+        (0..self.width)
+            .filter( |j| !pivots.contains(j) )
+            .map( |free|
+            {
+                let mut x = vec![T::ZERO; self.width];
+                x[free] = T::ONE;
+
+                for (i, p) in pivots.iter().enumerate()
+                {
+                    x[*p] = -reduced[i][free];
+                }
+
+                x
+            })
+            .collect()
+    }
+
+    // A solution of Ax = b (any one if there are several), None if inconsistent
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>>
+    {
+        if b.len() != self.height { panic!("Vector must have {} elements (got {})", self.height, b.len()); }
+
+        let mut column = Self::new(self.height, 1);
+        for i in 0..self.height { column[i][0] = b[i]; }
+
+        let mut augmented = self.augment(&column);
+        let pivots = augmented.row_reduce();
+
+        if pivots.last() == Some(&self.width) { return None; }
+
+        let mut x = vec![T::ZERO; self.width];
+
+        for (i, p) in pivots.iter().enumerate()
+        {
+            x[*p] = augmented[i][self.width];
+        }
+
+        return Some(x);
+    }
+
+    // [self | other]
+    fn augment(&self, other: &Self) -> Self
+    {
+        if self.height != other.height { panic!("Matrices must have the same height ({}/{})", self.height, other.height); }
+
+        let mut augmented = Self::new(self.height, self.width + other.width);
+
+        for i in 0..self.height
+        {
+            augmented[i][..self.width].copy_from_slice(&self[i]);
+            augmented[i][self.width..].copy_from_slice(&other[i]);
+        }
+
+        return augmented;
+    }
+}
+
+impl<T> ops::Mul for &Matrix<T> where T: Field + Default
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T>
+    {
+        if self.width != rhs.height { panic!("Mismatched dimensions ({}x{} * {}x{})", self.height, self.width, rhs.height, rhs.width); }
+
+        let mut product = Matrix::new(self.height, rhs.width);
+
+        for i in 0..self.height
+        {
+            for k in 0..self.width
+            {
+                let a = self[i][k];
+                if a.is_zero() { continue; }
+
+                for j in 0..rhs.width
+                {
+                    product[i][j] = product[i][j] + a * rhs[k][j];
+                }
+            }
+        }
+
+        return product;
+    }
+}
+
+impl<T> Clone for Matrix<T> where T: Copy
+{
+    fn clone(&self) -> Self
+    {
+        Matrix {
+            width: self.width,
+            height: self.height,
+            values: self.values.clone()
+        }
+    }
+}
+
+impl<T> From<&Vec<Vec<T>>> for Matrix<T> where T: Default + Copy
+{
+    fn from(values: &Vec<Vec<T>>) -> Self
+    {
+        assert_size(values);
+
+        let height = values.len();
+        let width  = values[0].len();
+
+        Matrix { width: width, height: height, values: values.concat() }
+    }
+}
+
+impl<T> ops::Index<usize> for Matrix<T>
+{
+    type Output = [T];
+
+    fn index(&self, index: usize) -> &Self::Output
+    {
+        if index >= self.height { panic!("Out of range"); }
+
+        &self.values[index * self.width..(index + 1) * self.width]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for Matrix<T>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output
+    {
+        if index >= self.height { panic!("Out of range"); }
+
+        &mut self.values[index * self.width..(index + 1) * self.width]
+    }
+}
+
+impl<T> cmp::PartialEq for Matrix<T> where T: PartialEq
+{
+    fn eq(&self, rhs: &Matrix<T>) -> bool
+    {
+        self.width == rhs.width && self.height == rhs.height && self.values == rhs.values
+    }
+}
+
+impl<T> fmt::Debug for Matrix<T> where T: fmt::Display
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        writeln!(f)?;
+
+        for i in 0..self.height
+        {
+            write!(f, "[")?;
+
+            for j in 0..self.width
+            {
+                write!(f, " {}", self[i][j])?;
+            }
+
+            writeln!(f, " ]")?;
+        }
+
+        write!(f, "")
+    }
+}
+
+fn assert_size<T>(values: &[Vec<T>])
+{
+    if values.is_empty() { panic!("A 0x0 matrix is not supported (use ::new)") }
+
+    let mut last_width = 0;
+
+    for i in 0..values.len()
+    {
+        if i == 0 { last_width = values[i].len() }
+        else
+        {
+            if last_width != values[i].len() { panic!("All rows must have the same size in a matrix") }
+        }
+    }
+
+    if last_width == 0 { panic!("A Nx0 matrix is not supported"); }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::Matrix;
+    use crate::math::{Field, GF2, AesField, ModP};
+
+    type F = ModP<101>;
+
+    fn modp(rows: &[&[i64]]) -> Matrix<F>
+    {
+        Matrix::from(&rows.iter().map( |r| r.iter().map( |v| F::from_i64(*v) ).collect() ).collect::<Vec<Vec<F>>>())
+    }
+
+    fn gf2(rows: &[&[u8]]) -> Matrix<GF2>
+    {
+        Matrix::from(&rows.iter().map( |r| r.iter().map( |v| GF2::from(*v) ).collect() ).collect::<Vec<Vec<GF2>>>())
+    }
+
+    #[test]
+    fn from()
+    {
+        let matrix = Matrix::<u8>::from(&vec![
+            vec![11, 12],
+            vec![21, 22]
+        ]);
+
+        assert_eq!(11, matrix[0][0]);
+        assert_eq!(12, matrix[0][1]);
+        assert_eq!(21, matrix[1][0]);
+        assert_eq!(22, matrix[1][1]);
+    }
+
+    #[test]
+    fn transpose()
+    {
+        let matrix = Matrix::<u8>::from(&vec![
+            vec![11, 12],
+            vec![21, 22]
+        ]);
+
+        let transposed = matrix.transpose();
+
+        assert_eq!(
+            Matrix::<u8>::from(&vec![
+                vec![11, 21],
+                vec![12, 22]
+            ]),
+            transposed
+        )
+    }
+
+    #[test]
+    fn mul()
+    {
+        let a = modp(&[&[1, 2], &[3, 4], &[5, 6]]);
+        let b = modp(&[&[1, 0, -1], &[2, 1, 0]]);
+
+        assert_eq!(modp(&[&[5, 2, -1], &[11, 4, -3], &[17, 6, -5]]), &a * &b);
+        assert_eq!(vec![F::new(5), F::new(11), F::new(17)], a.mul_vector(&[F::new(1), F::new(2)]));
+    }
+
+    #[test]
+    fn rank()
+    {
+        assert_eq!(2, modp(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]).rank());
+        assert_eq!(3, Matrix::<AesField>::identity(3).rank());
+        assert_eq!(1, gf2(&[&[1, 1], &[1, 1]]).rank());
+    }
+
+    #[test]
+    fn inverse()
+    {
+        let a = modp(&[&[2, 1, 1], &[1, 3, 2], &[1, 0, 0]]);
+        let inverse = a.inverse().unwrap();
+
+        assert_eq!(Matrix::identity(3), &a * &inverse);
+        assert_eq!(Matrix::identity(3), &inverse * &a);
+        assert!(modp(&[&[1, 2], &[2, 4]]).inverse().is_none());
+
+        // AES MixColumns and InvMixColumns are inverses of each other
+        let mix = Matrix::from(&[[2, 3, 1, 1], [1, 2, 3, 1], [1, 1, 2, 3], [3, 1, 1, 2]].iter()
+            .map( |r| r.iter().map( |v| AesField::new(*v) ).collect() )
+            .collect::<Vec<Vec<AesField>>>());
+        let inv_mix = Matrix::from(&[[14, 11, 13, 9], [9, 14, 11, 13], [13, 9, 14, 11], [11, 13, 9, 14]].iter()
+            .map( |r| r.iter().map( |v| AesField::new(*v) ).collect() )
+            .collect::<Vec<Vec<AesField>>>());
+
+        assert_eq!(Some(inv_mix), mix.inverse());
+    }
+
+    #[test]
+    fn kernel()
+    {
+        let a = gf2(&[&[1, 1, 0, 0], &[0, 1, 1, 0]]);
+        let kernel = a.kernel();
+
+        assert_eq!(2, kernel.len());
+
+        for x in &kernel
+        {
+            assert!(a.mul_vector(x).iter().all( |v| v.is_zero() ));
+        }
+
+        assert!(Matrix::<F>::identity(3).kernel().is_empty());
+    }
+
+    #[test]
+    fn solve()
+    {
+        let a = modp(&[&[1, 1, 1], &[0, 2, 5], &[2, 5, -1]]);
+        let b = [F::new(6), F::from_i64(-4), F::new(27)];
+
+        assert_eq!(Some(vec![F::new(5), F::new(3), F::from_i64(-2)]), a.solve(&b));
+
+        // x + y = 1 and x + y = 0 over GF(2)
+        assert_eq!(None, gf2(&[&[1, 1], &[1, 1]]).solve(&[GF2::ONE, GF2::ZERO]));
+
+        let under = gf2(&[&[1, 1, 0]]);
+        let x = under.solve(&[GF2::ONE]).unwrap();
+
+        assert_eq!(vec![GF2::ONE], under.mul_vector(&x));
+    }
+}
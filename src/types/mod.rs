@@ -0,0 +1,14 @@
+mod byte;
+mod bytes;
+mod bit_vec;
+mod matrix;
+mod secret_bytes;
+
+pub use byte::Byte;
+pub use bit_vec::{BitVec, BitOrder};
+pub use bytes::{Bytes, Cycle, CodecError};
+pub use bytes::base64::{Base64, Alphabet, Padding};
+pub(crate) use bytes::base64::Decoder as Base64Decoder;
+pub use matrix::Matrix;
+pub use secret_bytes::{SecretBytes, Revealed};
+pub(crate) use secret_bytes::{wipe, ct_eq};
\ No newline at end of file
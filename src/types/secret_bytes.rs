@@ -0,0 +1,196 @@
+use std::fmt;
+use std::ptr;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+use crate::types::{Byte, Bytes};
+
+// Key material: wiped on drop, redacted when formatted, compared in constant time
+// (the length is not considered secret)
+#[derive(Clone)]
+pub struct SecretBytes
+{
+    bytes: Box<[Byte]>
+}
+
+// Opt-in to print the secret, see SecretBytes::reveal
+pub struct Revealed<'a>(&'a SecretBytes);
+
+impl SecretBytes
+{
+    pub fn new(bytes: &[Byte]) -> SecretBytes
+    {
+        SecretBytes { bytes: Box::from(bytes) }
+    }
+
+    pub fn zeroed(size: usize) -> SecretBytes
+    {
+        SecretBytes { bytes: vec![0; size].into_boxed_slice() }
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.bytes.is_empty()
+    }
+
+    pub fn expose(&self) -> &[Byte]
+    {
+        &self.bytes
+    }
+
+    pub fn expose_mut(&mut self) -> &mut [Byte]
+    {
+        &mut self.bytes
+    }
+
+    pub fn reveal(&self) -> Revealed<'_>
+    {
+        Revealed(self)
+    }
+
+    pub fn ct_eq(&self, other: &[Byte]) -> bool
+    {
+        ct_eq(&self.bytes, other)
+    }
+
+    fn wipe(&mut self)
+    {
+        wipe(&mut self.bytes);
+    }
+}
+
+// Constant time in the content, for tags and MACs as well (the length is not considered secret)
+pub(crate) fn ct_eq(a: &[Byte], b: &[Byte]) -> bool
+{
+    if a.len() != b.len() { return false; }
+
+    let diff = a.iter()
+        .zip(b.iter())
+        .fold(0, |acc, (x, y)| acc | (x ^ y));
+
+    // Keep the comparison from being turned into an early exit
+    return unsafe { ptr::read_volatile(&diff) } == 0;
+}
+
+// Zeroes memory holding secrets, for the fixed-size copies kept outside of SecretBytes
+pub(crate) fn wipe(bytes: &mut [Byte])
+{
+    for b in bytes.iter_mut()
+    {
+        // Volatile so the writes to memory about to be freed are not optimized away
+        unsafe { ptr::write_volatile(b, 0) };
+    }
+
+    compiler_fence(Ordering::SeqCst);
+}
+
+impl Drop for SecretBytes
+{
+    fn drop(&mut self)
+    {
+        self.wipe();
+    }
+}
+
+impl From<&[Byte]> for SecretBytes
+{
+    fn from(bytes: &[Byte]) -> Self
+    {
+        SecretBytes::new(bytes)
+    }
+}
+
+impl From<Vec<Byte>> for SecretBytes
+{
+    fn from(bytes: Vec<Byte>) -> Self
+    {
+        SecretBytes { bytes: bytes.into_boxed_slice() }
+    }
+}
+
+impl From<Bytes> for SecretBytes
+{
+    fn from(bytes: Bytes) -> Self
+    {
+        SecretBytes::from(Vec::from(bytes))
+    }
+}
+
+impl PartialEq for SecretBytes
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.ct_eq(&other.bytes)
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl fmt::Display for SecretBytes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl fmt::Debug for SecretBytes
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        write!(f, "SecretBytes([REDACTED; {}])", self.len())
+    }
+}
+
+impl<'a> fmt::Display for Revealed<'a>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        for b in self.0.bytes.iter()
+        {
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::SecretBytes;
+
+    #[test]
+    fn format()
+    {
+        let secret = SecretBytes::new(&[0xde, 0xad, 0xbe, 0xef]);
+
+        assert_eq!("[REDACTED]", secret.to_string());
+        assert_eq!("SecretBytes([REDACTED; 4])", format!("{:?}", secret));
+        assert_eq!("deadbeef", secret.reveal().to_string());
+    }
+
+    #[test]
+    fn eq()
+    {
+        let secret = SecretBytes::new(&[0x01, 0x02, 0x03]);
+
+        assert_eq!(secret, SecretBytes::from(vec![0x01, 0x02, 0x03]));
+        assert_ne!(secret, SecretBytes::new(&[0x01, 0x02, 0x04]));
+        assert_ne!(secret, SecretBytes::new(&[0x01, 0x02]));
+        assert!(secret.ct_eq(&[0x01, 0x02, 0x03]));
+    }
+
+    #[test]
+    fn wipe()
+    {
+        let mut secret = SecretBytes::new(&[0xff; 16]);
+        secret.wipe();
+
+        assert_eq!(&[0x00; 16], secret.expose());
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+// Environment variable read when no seed is given on the command line
+pub const SEED_VARIABLE: &str = "CRYPTOPALS_SEED";
+
+pub fn parse_kv_string(string: &str) -> HashMap<String, String>
+{
+    string
+        .split("&")
+        .map( |pair| {
+            let kv: Vec<&str> = pair.split("=").collect();
+            (String::from(kv[0]), String::from(kv[1]))
+        })
+        .collect()
+}
+
+// Decimal or 0x-prefixed hexadecimal
+pub fn parse_seed(string: &str) -> Option<u64>
+{
+    let string = string.trim();
+
+    match string.strip_prefix("0x")
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None      => string.parse().ok()
+    }
+}
+
+// Without a seed one is drawn from the OS, it is returned so the run can be replayed
+pub fn seeded_rng(seed: Option<u64>) -> (StdRng, u64)
+{
+    let seed = seed.unwrap_or_else( || rand::thread_rng().gen() );
+
+    return (StdRng::seed_from_u64(seed), seed);
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn parse_kv_string()
+    {
+        const STRING: &str = "foo=bar&baz=qux&zap=zazzle";
+        let result = super::parse_kv_string(STRING);
+        let expected: HashMap<String, String> = 
+            [
+                (String::from("foo"), String::from("bar")),
+                (String::from("baz"), String::from("qux")),
+                (String::from("zap"), String::from("zazzle"))
+            ].iter().cloned().collect();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_seed()
+    {
+        assert_eq!(Some(1337), super::parse_seed("1337"));
+        assert_eq!(Some(0xdead), super::parse_seed(" 0xdead\n"));
+        assert_eq!(None, super::parse_seed("seed"));
+        assert_eq!(None, super::parse_seed("-1"));
+    }
+
+    #[test]
+    fn seeded_rng()
+    {
+        use rand::RngCore;
+
+        let (mut a, seed) = super::seeded_rng(None);
+        let (mut b, _)    = super::seeded_rng(Some(seed));
+
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_eq!(42, super::seeded_rng(Some(42)).1);
+    }
+}
\ No newline at end of file